@@ -0,0 +1,459 @@
+//! validates the data files embedded into the crate and
+//! generates the `UnitId` enum from `Units.toml`
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write as _,
+    fs,
+    path::Path,
+};
+
+use toml::{Table, Value};
+
+// the checks the crate runs on the loaded phases
+#[path = "src/api/teams/check.rs"]
+mod check;
+#[expect(dead_code, reason = "only parsing of mission IDs is needed")]
+#[path = "src/api/teams/mission_id.rs"]
+mod mission_id;
+
+use check::{check_id, check_relic, is_fleet};
+use mission_id::Alignment;
+
+const DATA: &str = "assets/data";
+const SQUAD_SIZE: usize = 5;
+const FLEET_SIZE: usize = 8;
+
+fn main() {
+    println!("cargo::rerun-if-changed={DATA}");
+
+    let units = load("Units.toml");
+    let units = units
+        .get("Unit")
+        .and_then(Value::as_array)
+        .map(|units| units.iter().filter_map(Value::as_table).collect::<Vec<_>>())
+        .unwrap_or_default();
+    let ids = units
+        .iter()
+        .filter_map(|unit| unit.get("id").and_then(Value::as_str))
+        .collect::<Vec<_>>();
+    let known = ids
+        .iter()
+        .map(|id| id.to_ascii_lowercase())
+        .collect::<HashSet<_>>();
+    // lowercase unit IDs by tag
+    let mut tags: HashMap<String, Vec<String>> = HashMap::new();
+    for unit in &units {
+        let id = unit.get("id").and_then(Value::as_str).unwrap_or_default();
+        for tag in unit
+            .get("tags")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+        {
+            tags.entry(tag.to_ascii_lowercase())
+                .or_default()
+                .push(id.to_ascii_lowercase());
+        }
+    }
+
+    let mut errors = Vec::new();
+    check_units(&units, &mut errors);
+    // (phase, name, unlocking mission) of each bonus planet
+    let mut bonus = Vec::new();
+    // (phase, mission ID, bonus planet) of each unlocking mission
+    let mut unlocks = Vec::new();
+    for num in 1..=6 {
+        let file = format!("Phase{num}.toml");
+        let phase = load(&file);
+        for (side, planet) in &phase {
+            let name = planet.get("name").and_then(Value::as_str).unwrap_or("?");
+            let relic = planet.get("relic").and_then(as_relic);
+            if relic.is_none() {
+                errors.push(format!("{file}: {side} planet has no relic requirement"));
+            }
+            let Some(alignment) = Alignment::ALL
+                .into_iter()
+                .find(|alignment| side.starts_with(alignment.letter()))
+            else {
+                errors.push(format!("{file}: unknown planet {side}"));
+                continue;
+            };
+            if alignment == Alignment::Bonus {
+                let unlock = planet.get("unlock").and_then(Value::as_str);
+                bonus.push((num, name.to_owned(), unlock.map(str::to_owned)));
+            }
+            for mission in missions(planet) {
+                let id = mission.get("id").and_then(Value::as_str).unwrap_or("?");
+                let at = format!("{file}: {side} mission {id}");
+                let mission_name = mission.get("name").and_then(Value::as_str).unwrap_or("?");
+                if let Some(planet) = mission.get("unlocks").and_then(Value::as_str) {
+                    unlocks.push((num, id.to_owned(), planet.to_owned()));
+                }
+                let own = mission.get("relic").and_then(as_relic);
+                let problems = [
+                    check_relic(id, mission_name, name, own, relic),
+                    check_id(id, mission_name, name, num, alignment),
+                ];
+                errors.extend(
+                    problems
+                        .into_iter()
+                        .flatten()
+                        .map(|p| format!("{file}: {p}")),
+                );
+                check_mission(mission, &known, &tags, &at, &mut errors);
+            }
+        }
+
+        check_operations(num, &phase, &known, &mut errors);
+    }
+    check_unlocks(&bonus, &unlocks, &mut errors);
+    assert!(
+        errors.is_empty(),
+        "invalid data files:\n{}",
+        errors.join("\n")
+    );
+
+    let out = std::env::var("OUT_DIR").expect("cargo sets OUT_DIR");
+    fs::write(Path::new(&out).join("unit_id.rs"), unit_id(&ids))
+        .expect("failed to write unit_id.rs");
+}
+
+/// checks that the operations of phase `num` use known units
+/// and match the planets of `phase` in name and relic
+fn check_operations(num: usize, phase: &Table, known: &HashSet<String>, errors: &mut Vec<String>) {
+    let file = format!("Operations{num}.toml");
+    for (side, planet) in &load(&file) {
+        for key in ["name", "relic"] {
+            let own = planet.get(key);
+            let expected = phase.get(side).and_then(|planet| planet.get(key));
+            if own != expected {
+                errors.push(format!(
+                    "{file}: {side} {key} is {}, but Phase{num}.toml has {}",
+                    own.map_or("missing".to_owned(), Value::to_string),
+                    expected.map_or("missing".to_owned(), Value::to_string),
+                ));
+            }
+        }
+        for slot in planet
+            .get("operation")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|op| op.get("slots").and_then(Value::as_array))
+            .flatten()
+            .filter_map(Value::as_str)
+        {
+            if !known.contains(&slot.to_ascii_lowercase()) {
+                errors.push(format!("{file}: {side} operation: unknown unit `{slot}`"));
+            }
+        }
+    }
+}
+
+fn load(file: &str) -> Table {
+    let path = Path::new(DATA).join(file);
+    let s = fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()));
+    s.parse()
+        .unwrap_or_else(|err| panic!("failed to parse {}: {err}", path.display()))
+}
+
+/// a relic requirement, `None` if missing or out of range
+fn as_relic(value: &Value) -> Option<u8> {
+    value
+        .as_integer()
+        .and_then(|relic| u8::try_from(relic).ok())
+}
+
+fn missions(planet: &Value) -> impl Iterator<Item = &Table> {
+    planet
+        .get("mission")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_table)
+}
+
+fn check_mission(
+    mission: &Table,
+    known: &HashSet<String>,
+    tags: &HashMap<String, Vec<String>>,
+    at: &str,
+    errors: &mut Vec<String>,
+) {
+    let fleet = mission
+        .get("name")
+        .and_then(Value::as_str)
+        .is_some_and(is_fleet);
+    let slots = mission
+        .get("team")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    let size = if fleet { FLEET_SIZE } else { SQUAD_SIZE };
+    if slots.len() != size {
+        errors.push(format!(
+            "{at}: team has {} units instead of {size}",
+            slots.len()
+        ));
+    }
+    let team = slot_units(slots, tags, at, errors);
+    for unit in &team {
+        if !unit.is_empty() && unit != "[ph]" && !known.contains(unit) {
+            errors.push(format!("{at}: unknown unit `{unit}`"));
+        }
+    }
+
+    for requirement in mission
+        .get("requirements")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        let Some(slot) = requirement
+            .get("slot")
+            .and_then(Value::as_integer)
+            .and_then(|slot| usize::try_from(slot).ok())
+            .filter(|slot| *slot < slots.len())
+        else {
+            errors.push(format!("{at}: requirement for a slot not in the team"));
+            continue;
+        };
+        for zeta in requirement
+            .get("zetas")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            let zeta = zeta.as_str().unwrap_or_default();
+            if !is_omicron(zeta) {
+                errors.push(format!("{at}: malformed zeta `{zeta}` of slot {slot}"));
+            }
+        }
+    }
+
+    for enemy in mission
+        .get("waves")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|wave| wave.get("enemies").and_then(Value::as_array))
+        .flatten()
+        .filter_map(Value::as_str)
+    {
+        if !known.contains(&enemy.to_ascii_lowercase()) {
+            errors.push(format!("{at}: unknown enemy `{enemy}`"));
+        }
+    }
+
+    for reward in mission
+        .get("rewards")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        if let Some(unit) = reward.get("unit").and_then(Value::as_str)
+            && !known.contains(&unit.to_ascii_lowercase())
+        {
+            errors.push(format!("{at}: unknown reward unit `{unit}`"));
+        }
+    }
+
+    for omicrons in mission
+        .get("omicrons")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        let unit = omicrons
+            .get("unit")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        if !known.contains(&unit.to_ascii_lowercase()) {
+            errors.push(format!("{at}: unknown omicron unit `{unit}`"));
+        }
+        for omi in omicrons
+            .get("omis")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            let omi = omi.as_str().unwrap_or_default();
+            if !is_omicron(omi) {
+                errors.push(format!("{at}: malformed omicron `{omi}` of `{unit}`"));
+            }
+        }
+    }
+}
+
+/// lowercase IDs of all units which may be part of the team,
+/// slots are unit IDs, lists of unit IDs or `{ tag = "..." }`
+fn slot_units(
+    slots: &[Value],
+    tags: &HashMap<String, Vec<String>>,
+    at: &str,
+    errors: &mut Vec<String>,
+) -> Vec<String> {
+    let mut team = Vec::new();
+    for slot in slots {
+        match slot {
+            Value::String(unit) => team.push(unit.to_ascii_lowercase()),
+            Value::Array(choice) if !choice.is_empty() => {
+                for unit in choice {
+                    match unit.as_str() {
+                        Some(unit) if !unit.is_empty() && unit != "[ph]" => {
+                            team.push(unit.to_ascii_lowercase());
+                        }
+                        _ => errors.push(format!("{at}: invalid choice {unit}")),
+                    }
+                }
+            }
+            Value::Table(slot) => match slot.get("tag").and_then(Value::as_str) {
+                Some(tag) => match tags.get(&tag.to_ascii_lowercase()) {
+                    Some(units) => team.extend(units.iter().cloned()),
+                    None => errors.push(format!("{at}: no unit has the tag `{tag}`")),
+                },
+                None => errors.push(format!("{at}: invalid slot {slot}")),
+            },
+            slot => errors.push(format!("{at}: invalid slot {slot}")),
+        }
+    }
+    team
+}
+
+/// every unit needs a unique game ID to import rosters and
+/// aliases must not be the ID, name or alias of another unit
+fn check_units(units: &[&Table], errors: &mut Vec<String>) {
+    let mut base_ids = HashSet::new();
+    let mut names: HashMap<String, &str> = HashMap::new();
+    for unit in units {
+        let id = unit.get("id").and_then(Value::as_str).unwrap_or_default();
+        match unit.get("base_id").and_then(Value::as_str) {
+            Some(base_id) if !base_ids.insert(base_id) => {
+                errors.push(format!("Units.toml: {id}: duplicate base ID `{base_id}`"));
+            }
+            Some(_) => {}
+            None => errors.push(format!("Units.toml: {id} has no base ID")),
+        }
+        let name = unit.get("name").and_then(Value::as_str).unwrap_or_default();
+        for name in [id, name] {
+            names.insert(name.to_ascii_lowercase(), id);
+        }
+    }
+    for unit in units {
+        let id = unit.get("id").and_then(Value::as_str).unwrap_or_default();
+        for alias in unit
+            .get("aliases")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+        {
+            match names.insert(alias.to_ascii_lowercase(), id) {
+                Some(other) if other != id => errors.push(format!(
+                    "Units.toml: {id}: alias `{alias}` is already used by {other}"
+                )),
+                Some(_) => errors.push(format!("Units.toml: {id}: redundant alias `{alias}`")),
+                None => {}
+            }
+        }
+    }
+}
+
+/// bonus planets must be unlocked by a mission of an earlier
+/// phase which in turn names the planet it unlocks
+fn check_unlocks(
+    bonus: &[(usize, String, Option<String>)],
+    unlocks: &[(usize, String, String)],
+    errors: &mut Vec<String>,
+) {
+    for (num, name, unlock) in bonus {
+        let Some(unlock) = unlock else {
+            errors.push(format!(
+                "Phase{num}.toml: bonus planet {name} has no unlock"
+            ));
+            continue;
+        };
+        if !unlocks
+            .iter()
+            .any(|(phase, id, planet)| phase < num && id == unlock && planet == name)
+        {
+            errors.push(format!(
+                "Phase{num}.toml: {name} is unlocked by {unlock}, \
+                 but no earlier mission {unlock} unlocks {name}"
+            ));
+        }
+    }
+    for (num, id, planet) in unlocks {
+        if !bonus
+            .iter()
+            .any(|(_, name, unlock)| name == planet && unlock.as_ref() == Some(id))
+        {
+            errors.push(format!(
+                "Phase{num}.toml: {id} unlocks {planet}, which is no bonus planet unlocked by it"
+            ));
+        }
+    }
+}
+
+/// `b`, `l`, `s<num>` or `u<num>`, case insensitive
+fn is_omicron(s: &str) -> bool {
+    let s = s.to_ascii_lowercase();
+    match s.split_at_checked(1) {
+        Some(("b" | "l", "")) => true,
+        Some(("s" | "u", num)) => !num.is_empty() && num.chars().all(|c| c.is_ascii_digit()),
+        _ => false,
+    }
+}
+
+fn unit_id(ids: &[&str]) -> String {
+    let variants = ids.iter().map(|id| variant(id)).collect::<Vec<_>>();
+    let mut seen = HashSet::new();
+    for (id, variant) in ids.iter().zip(&variants) {
+        assert!(
+            seen.insert(variant),
+            "unit `{id}` clashes with another unit as `UnitId::{variant}`"
+        );
+    }
+
+    let mut s = String::from(
+        "/// IDs of all units in `Units.toml`, generated by `build.rs`\n\
+         #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n\
+         pub enum UnitId {\n",
+    );
+    for variant in &variants {
+        writeln!(s, "    {variant},").expect("writing to a String never fails");
+    }
+    s.push_str("}\n\nimpl UnitId {\n    pub const ALL: &[Self] = &[\n");
+    for variant in &variants {
+        writeln!(s, "        Self::{variant},").expect("writing to a String never fails");
+    }
+    s.push_str("    ];\n\n");
+    s.push_str("    #[expect(clippy::too_many_lines, reason = \"one arm per unit\")]\n");
+    s.push_str("    pub fn as_str(self) -> &'static str {\n        match self {\n");
+    for (id, variant) in ids.iter().zip(&variants) {
+        writeln!(s, "            Self::{variant} => {id:?},")
+            .expect("writing to a String never fails");
+    }
+    s.push_str("        }\n    }\n}\n");
+    s
+}
+
+/// `carthShip` becomes `CarthShip`, `4lom` becomes `U4lom`
+fn variant(id: &str) -> String {
+    let mut variant = String::new();
+    for part in id.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            variant.push(first.to_ascii_uppercase());
+            variant.extend(chars);
+        }
+    }
+    if !variant.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        variant.insert(0, 'U');
+    }
+    variant
+}
@@ -0,0 +1,236 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Mission, Phase};
+
+/// guild members assigned to missions by officers
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Assignments {
+    /// assigned players keyed by mission ID
+    ///
+    /// mission IDs already encode their phase, so
+    /// a single map covers the whole TB
+    pub missions: BTreeMap<String, Vec<String>>,
+}
+
+impl Assignments {
+    /// storage key of the assignment board
+    pub const KEY: &str = "assignments";
+
+    pub fn from_toml(s: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(s)
+    }
+
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string(self)
+    }
+
+    pub fn players(&self, mission: &Mission) -> &[String] {
+        self.missions
+            .get(&mission.id)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    pub fn assign(&mut self, mission: &Mission, player: &str) {
+        let player = player.trim();
+        if player.is_empty() {
+            return;
+        }
+        let players = self.missions.entry(mission.id.clone()).or_default();
+        if !players.iter().any(|p| p.eq_ignore_ascii_case(player)) {
+            players.push(player.to_owned());
+        }
+    }
+
+    pub fn unassign(&mut self, mission: &Mission, player: &str) {
+        if let Some(players) = self.missions.get_mut(&mission.id) {
+            players.retain(|p| p != player);
+            if players.is_empty() {
+                self.missions.remove(&mission.id);
+            }
+        }
+    }
+
+    /// removes all assignments to missions of `phase`
+    pub fn clear_phase(&mut self, phase: &Phase) {
+        for mission in phase.iter().flat_map(|planet| &planet.missions) {
+            self.missions.remove(&mission.id);
+        }
+    }
+
+    /// missions of `phase` without any assigned player,
+    /// missions sharing an ID are only listed once
+    pub fn unassigned<'a>(&self, phase: &'a Phase) -> Vec<&'a Mission> {
+        let mut unassigned: Vec<&Mission> = Vec::new();
+        for mission in phase.iter().flat_map(|planet| &planet.missions) {
+            if self.players(mission).is_empty() && !unassigned.iter().any(|m| m.id == mission.id) {
+                unassigned.push(mission);
+            }
+        }
+        unassigned
+    }
+
+    /// the assignment of `phase` as a text order list,
+    /// one line per mission followed by a line per player
+    pub fn to_order_list(&self, phase: &Phase, num: usize) -> String {
+        let mut missions: Vec<(&str, String)> = Vec::new();
+        let mut players: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+
+        for planet in phase {
+            for mission in &planet.missions {
+                let assigned = self.players(mission);
+                if assigned.is_empty() || missions.iter().any(|(id, _)| *id == mission.id) {
+                    continue;
+                }
+                for player in assigned {
+                    players.entry(player).or_default().push(&mission.id);
+                }
+                missions.push((
+                    &mission.id,
+                    format!("{} {}: {}", mission.id, mission.name, assigned.join(", ")),
+                ));
+            }
+        }
+
+        let mut list = format!("Phase {num} orders\n\nMissions:\n");
+        for (_, line) in missions {
+            list.push_str(&format!("- {line}\n"));
+        }
+        list.push_str("\nPlayers:\n");
+        for (player, ids) in players {
+            list.push_str(&format!("- {player}: {}\n", ids.join(", ")));
+        }
+
+        let unassigned = self.unassigned(phase);
+        if !unassigned.is_empty() {
+            list.push_str("\nUnassigned: ");
+            list.push_str(
+                &unassigned
+                    .iter()
+                    .map(|m| m.id.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            );
+            list.push('\n');
+        }
+        list
+    }
+
+    /// reads the assigned missions back from an order list written
+    /// by [`Self::to_order_list`], `None` if `s` is no order list
+    pub fn from_order_list(s: &str) -> Option<Self> {
+        let mut lines = s.lines().map(str::trim);
+        lines.find(|line| *line == "Missions:")?;
+        let mut missions = BTreeMap::new();
+        for line in lines.map_while(|line| line.strip_prefix("- ")) {
+            // `<ID> <name>: <player>, <player>`, player names may contain `: `
+            let (id, rest) = line.split_once(' ')?;
+            let (_, players) = rest.split_once(": ")?;
+            missions.insert(
+                id.to_owned(),
+                players.split(", ").map(str::to_owned).collect(),
+            );
+        }
+        Some(Self { missions })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::teams::test_phase;
+
+    const PHASE: &str = r#"
+Light = { name = "Coruscant", mission = [] }
+
+[Dark]
+name = "Mustafar"
+
+[[Dark.mission]]
+id = "P1DCM1"
+name = "Left Combat Mission"
+team = []
+note = []
+
+[[Dark.mission]]
+id = "P1DCM1"
+name = "Left Combat Mission"
+team = []
+note = []
+
+[[Dark.mission]]
+id = "P1DSM"
+name = "Special Mission"
+team = []
+note = []
+
+[Mixed]
+name = "Corellia"
+
+[[Mixed.mission]]
+id = "P1MF"
+name = "Fleet"
+team = []
+note = []
+"#;
+
+    fn ids<'a>(missions: &[&'a Mission]) -> Vec<&'a str> {
+        missions.iter().map(|m| m.id.as_str()).collect()
+    }
+
+    #[test]
+    fn order_lists_round_trip() {
+        let phase = test_phase(PHASE);
+        let [cm, sm, fleet] = ["P1DCM1", "P1DSM", "P1MF"].map(|id| {
+            phase
+                .iter()
+                .flat_map(|planet| &planet.missions)
+                .find(|m| m.id == id)
+                .expect("mission in phase")
+        });
+        let mut assignments = Assignments::default();
+        assignments.assign(cm, "Zed");
+        assignments.assign(cm, "Ann: the Second");
+        assignments.assign(sm, "Zed");
+        assignments.assign(fleet, "Bo");
+
+        let list = assignments.to_order_list(&phase, 1);
+        let imported = Assignments::from_order_list(&list).expect("an order list");
+        assert_eq!(
+            imported.missions, assignments.missions,
+            "same players in the same order:\n{list}"
+        );
+        assert!(
+            Assignments::from_order_list("[missions]").is_none(),
+            "TOML is no order list"
+        );
+    }
+
+    #[test]
+    fn unassigned_ignores_assigned_missions() {
+        let phase = test_phase(PHASE);
+        let mut assignments = Assignments::default();
+        assert_eq!(
+            ids(&assignments.unassigned(&phase)),
+            ["P1DCM1", "P1DSM", "P1MF"],
+            "alternative teams are listed once"
+        );
+
+        let sm = &phase.dark.missions[2];
+        assignments.assign(sm, "Zed");
+        assert_eq!(
+            ids(&assignments.unassigned(&phase)),
+            ["P1DCM1", "P1MF"],
+            "the special mission is assigned"
+        );
+
+        assignments.clear_phase(&phase);
+        assert_eq!(
+            assignments.unassigned(&phase).len(),
+            3,
+            "nothing is assigned after clearing the phase"
+        );
+    }
+}
@@ -0,0 +1,343 @@
+//! chat platform independent handler for `/tb` commands
+
+#[cfg(all(feature = "webhook", not(target_arch = "wasm32")))]
+mod webhook;
+
+#[cfg(all(feature = "webhook", not(target_arch = "wasm32")))]
+pub use webhook::*;
+
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::{Mission, Teams, Units};
+
+/// maximum length of a field value, longer values are cut
+pub const FIELD_LIMIT: usize = 1024;
+
+const HELP: &str = "Usage:
+`/tb mission <ID>` e.g. `/tb mission P2DCM1`
+`/tb phase <number> [planet]` e.g. `/tb phase 3 light`
+`/tb unit <ID, name or alias>` e.g. `/tb unit reva`";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Mission(String),
+    /// number of the phase and optionally a planet
+    /// name or dark, mixed, light or bonus
+    Phase(usize, Option<String>),
+    Unit(String),
+    Help,
+}
+
+impl FromStr for Command {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = match s.split_at_checked(3) {
+            Some((prefix, rest)) if prefix.eq_ignore_ascii_case("/tb") => rest.trim(),
+            _ => s,
+        };
+        let (command, args) = s.split_once(char::is_whitespace).unwrap_or((s, ""));
+        let args = args.trim();
+        match command.to_ascii_lowercase().as_str() {
+            "" | "help" => Ok(Self::Help),
+            "mission" if !args.is_empty() => Ok(Self::Mission(args.to_owned())),
+            "unit" if !args.is_empty() => Ok(Self::Unit(args.to_owned())),
+            "phase" => {
+                let (num, planet) = args.split_once(char::is_whitespace).unwrap_or((args, ""));
+                let num = num
+                    .parse()
+                    .map_err(|err| format!("`{num}` is not a phase number: {err}"))?;
+                let planet = planet.trim();
+                Ok(Self::Phase(
+                    num,
+                    (!planet.is_empty()).then(|| planet.to_owned()),
+                ))
+            }
+            "mission" | "unit" => Err(format!("`{command}` needs an argument")),
+            _ => Err(format!("unknown command `{command}`")),
+        }
+    }
+}
+
+/// a formatted response, similar to a Discord embed
+#[derive(Debug, Clone, Serialize)]
+pub struct Reply {
+    pub title: String,
+    pub text: String,
+    pub fields: Vec<Field>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Field {
+    pub name: String,
+    pub value: String,
+    pub inline: bool,
+}
+
+impl Field {
+    fn new(name: &str, value: &str, inline: bool) -> Self {
+        Self {
+            name: name.to_owned(),
+            value: truncate(value, FIELD_LIMIT),
+            inline,
+        }
+    }
+}
+
+impl Reply {
+    fn error(text: &str) -> Self {
+        Self {
+            title: "Error".to_owned(),
+            text: format!("{text}\n\n{HELP}"),
+            fields: Vec::new(),
+        }
+    }
+
+    /// the reply as plain text, for platforms without embeds
+    pub fn to_text(&self) -> String {
+        let mut text = format!("**{}**\n{}", self.title, self.text);
+        for field in &self.fields {
+            text.push_str(&format!("\n**{}**\n{}", field.name, field.value));
+        }
+        text
+    }
+}
+
+/// parses `input` and answers it
+pub fn respond(teams: &Teams, units: &Units, input: &str) -> Reply {
+    match input.parse::<Command>() {
+        Ok(command) => command.respond(teams, units),
+        Err(err) => Reply::error(&err),
+    }
+}
+
+impl Command {
+    pub fn respond(&self, teams: &Teams, units: &Units) -> Reply {
+        match self {
+            Self::Help => Reply {
+                title: "TB Bot".to_owned(),
+                text: HELP.to_owned(),
+                fields: Vec::new(),
+            },
+            Self::Mission(id) => mission(teams, units, id),
+            Self::Phase(num, planet) => phase(teams, *num, planet.as_deref()),
+            Self::Unit(unit) => unit_reply(teams, units, unit),
+        }
+    }
+}
+
+fn mission(teams: &Teams, units: &Units, id: &str) -> Reply {
+    let found = teams
+        .missions()
+        .filter(|(_, _, m)| m.id.eq_ignore_ascii_case(id))
+        .collect::<Vec<_>>();
+    let Some((num, planet, first)) = found.first() else {
+        return Reply::error(&format!("mission `{id}` not found"));
+    };
+
+    let export = first.export(units);
+    let mut fields = vec![
+        Field::new("Team", &export.units.join(", "), false),
+        Field::new("Requirement", &export.requirement(), true),
+    ];
+    if !export.omicrons.is_empty() {
+        fields.push(Field::new("Omicrons", &export.omicrons.join("\n"), true));
+    }
+    if let Some(videos) = first.videos.as_ref().filter(|v| !v.is_empty()) {
+        let links = videos
+            .iter()
+            .map(|v| format!("[{}](<{}>)", v.source, v.url))
+            .collect::<Vec<_>>();
+        fields.push(Field::new("Videos", &links.join("\n"), true));
+    }
+    for (i, (_, _, alternative)) in found.iter().enumerate().skip(1) {
+        fields.push(Field::new(
+            &format!("Alternative {i}"),
+            &alternative.export(units).units.join(", "),
+            false,
+        ));
+    }
+
+    Reply {
+        title: format!("{} {}", export.id, export.name),
+        text: format!("Phase {num}, {}\n{}", planet.name, export.note.join("\n")),
+        fields,
+    }
+}
+
+fn phase(teams: &Teams, num: usize, planet: Option<&str>) -> Reply {
+    let Some(phase) = teams.phases.get(num.wrapping_sub(1)) else {
+        return Reply::error(&format!("there is no phase {num}"));
+    };
+    let planets = match planet.map(str::to_ascii_lowercase).as_deref() {
+        None => phase.iter().collect(),
+        Some("dark") => vec![&phase.dark],
+        Some("mixed") => vec![&phase.mixed],
+        Some("light") => vec![&phase.light],
+        Some("bonus") => phase.bonus.iter().collect(),
+        Some(name) => phase
+            .iter()
+            .filter(|p| p.name.eq_ignore_ascii_case(name))
+            .collect(),
+    };
+    if planets.is_empty() {
+        return Reply::error(&format!(
+            "phase {num} has no planet `{}`",
+            planet.unwrap_or_default()
+        ));
+    }
+
+    Reply {
+        title: format!("Phase {num}"),
+        text: String::new(),
+        fields: planets
+            .into_iter()
+            .map(|planet| {
+                let mut ids: Vec<&Mission> = Vec::new();
+                for mission in &planet.missions {
+                    if !ids.iter().any(|m| m.id == mission.id) {
+                        ids.push(mission);
+                    }
+                }
+                let list = ids.iter().map(|m| summary(m)).collect::<Vec<_>>();
+                Field::new(&planet.name, &list.join("\n"), false)
+            })
+            .collect(),
+    }
+}
+
+fn unit_reply(teams: &Teams, units: &Units, s: &str) -> Reply {
+    let Some(unit) = units.find(s) else {
+        return Reply::error(&format!("unit `{s}` not found"));
+    };
+
+    let mut fields = Vec::new();
+    if !unit.omicrons.is_empty() {
+        let omicrons = unit
+            .omicrons
+            .iter()
+            .map(|o| o.name.as_str())
+            .collect::<Vec<_>>();
+        fields.push(Field::new("Omicrons", &omicrons.join("\n"), true));
+    }
+    let missions = teams
+        .missions_with(&unit.id)
+        .map(|(num, _, mission)| format!("Phase {num}: {}", summary(mission)))
+        .collect::<Vec<_>>();
+    let missions = if missions.is_empty() {
+        "not used in any mission".to_owned()
+    } else {
+        missions.join("\n")
+    };
+    fields.push(Field::new("Missions", &missions, false));
+
+    Reply {
+        title: unit.name.clone(),
+        text: unit.aliases.join(", "),
+        fields,
+    }
+}
+
+/// e.g. `P1DCM1 Left Combat Mission (R5)`
+fn summary(mission: &Mission) -> String {
+    match mission.relic() {
+        Some(relic) => format!("`{}` {} (R{relic})", mission.id, mission.name),
+        None => format!("`{}` {}", mission.id, mission.name),
+    }
+}
+
+fn truncate(s: &str, limit: usize) -> String {
+    if s.chars().count() <= limit {
+        return s.to_owned();
+    }
+    let mut cut = s.chars().take(limit - 1).collect::<String>();
+    cut.push('…');
+    cut
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commands_are_parsed() {
+        let parsed = [
+            "/tb mission P2DCM1",
+            "/tb phase 3 light",
+            "/tb phase 3",
+            "/tb unit reva",
+            "  /TB  UNIT  GL Rey ",
+            "/tb",
+            "/tb help",
+        ]
+        .map(str::parse::<Command>);
+        assert_eq!(
+            parsed,
+            [
+                Ok(Command::Mission("P2DCM1".to_owned())),
+                Ok(Command::Phase(3, Some("light".to_owned()))),
+                Ok(Command::Phase(3, None)),
+                Ok(Command::Unit("reva".to_owned())),
+                Ok(Command::Unit("GL Rey".to_owned())),
+                Ok(Command::Help),
+                Ok(Command::Help),
+            ],
+            "valid commands"
+        );
+    }
+
+    #[test]
+    fn bad_input_is_rejected() {
+        for input in [
+            "/tb mission",
+            "/tb unit ",
+            "/tb phase",
+            "/tb phase three",
+            "/tb dance",
+        ] {
+            assert!(input.parse::<Command>().is_err(), "{input} is invalid");
+        }
+    }
+
+    #[test]
+    fn commands_are_answered() {
+        let units = Units::load().expect("units load");
+        let teams = Teams::load(&units).expect("data files load");
+        let reply = |input: &str| respond(&teams, &units, input);
+
+        let mission = reply("/tb mission p2lcm1");
+        assert!(mission.title.starts_with("P2LCM1 "), "{}", mission.title);
+        assert!(mission.text.starts_with("Phase 2, "), "{}", mission.text);
+        assert_eq!(mission.fields[0].name, "Team", "team first");
+
+        let phase = reply("/tb phase 3 light");
+        let planets = phase
+            .fields
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(planets, ["Kashyyyk"], "only the light side planet");
+
+        let unit = reply("/tb unit reva");
+        assert_eq!(unit.title, "Third Sister", "found by ID");
+        let missions = unit.fields.iter().find(|f| f.name == "Missions");
+        assert!(
+            missions.is_some_and(|f| f.value.contains("P1DCM3")),
+            "{missions:?}"
+        );
+
+        for input in [
+            "/tb mission P9XCM",
+            "/tb phase 7",
+            "/tb phase 3 hoth",
+            "/tb dance",
+        ] {
+            let reply = reply(input);
+            assert_eq!(reply.title, "Error", "{input} fails");
+            assert!(reply.text.contains("Usage:"), "{input} shows the usage");
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use serde_json::json;
+
+use super::Reply;
+
+/// posts replies to a Discord compatible webhook
+pub struct Webhook {
+    url: String,
+    client: reqwest::blocking::Client,
+}
+
+impl Webhook {
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_owned(),
+            client: reqwest::blocking::Client::new(),
+        }
+    }
+
+    pub fn send(&self, reply: &Reply) -> Result<(), reqwest::Error> {
+        let body = json!({
+            "embeds": [{
+                "title": reply.title,
+                "description": reply.text,
+                "fields": reply.fields,
+            }],
+        });
+        self.client
+            .post(&self.url)
+            .json(&body)
+            .send()?
+            .error_for_status()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use super::*;
+    use crate::{Teams, Units, api::mock::serve};
+
+    #[test]
+    fn replies_are_posted_as_embeds() {
+        let units = Units::load().expect("units load");
+        let teams = Teams::load(&units).expect("data files load");
+        let reply = super::super::respond(&teams, &units, "/tb unit GL Rey");
+
+        let (sender, receiver) = mpsc::channel();
+        let url = serve(1, move |endpoint, body| {
+            sender
+                .send((endpoint.to_owned(), body.to_owned()))
+                .expect("an open channel");
+            String::new()
+        });
+        Webhook::new(&format!("{url}/webhook"))
+            .send(&reply)
+            .expect("a posted reply");
+
+        let (endpoint, body) = receiver.recv().expect("a request");
+        let body: serde_json::Value = serde_json::from_str(&body).expect("a JSON body");
+        assert_eq!(endpoint, "/webhook", "posted to the webhook URL");
+        assert_eq!(body["embeds"][0]["title"], "Rey", "title");
+        assert_eq!(body["embeds"][0]["description"], "GL Rey", "aliases");
+        assert_eq!(body["embeds"][0]["fields"][0]["name"], "Missions", "fields");
+    }
+}
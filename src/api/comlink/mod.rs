@@ -0,0 +1,450 @@
+//! client for a self-hosted [swgoh-comlink](https://github.com/swgoh-utils/swgoh-comlink)
+//! compatible service
+
+mod response;
+
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::json;
+
+use crate::{Guild, Roster, RosterUnit, Units};
+
+use response::{GuildResponse, PlayerResponse};
+
+const CHARACTER_GP: &str = "STAT_CHARACTER_GALACTIC_POWER_ACQUIRED_NAME";
+const SHIP_GP: &str = "STAT_SHIP_GALACTIC_POWER_ACQUIRED_NAME";
+/// rosters change with every farmed unit, older responses are fetched again
+const DEFAULT_MAX_AGE: Duration = Duration::from_secs(60 * 60);
+/// ability level granting the zeta, abilities without one
+/// reaching it are recorded as well
+const ZETA_LEVEL: u8 = 8;
+/// ability level granting the omicron
+const OMICRON_LEVEL: u8 = 9;
+
+#[derive(Debug)]
+pub enum ComlinkError {
+    Http(reqwest::Error),
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    /// the player is not in a guild
+    NoGuild(String),
+}
+
+impl Display for ComlinkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Http(err) => write!(f, "request failed: {err}"),
+            Self::Io(err) => write!(f, "cache failed: {err}"),
+            Self::Json(err) => write!(f, "invalid response: {err}"),
+            Self::NoGuild(ally_code) => write!(f, "player {ally_code} is not in a guild"),
+        }
+    }
+}
+
+impl std::error::Error for ComlinkError {}
+
+impl From<reqwest::Error> for ComlinkError {
+    fn from(err: reqwest::Error) -> Self {
+        Self::Http(err)
+    }
+}
+
+impl From<std::io::Error> for ComlinkError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for ComlinkError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
+
+pub struct Comlink {
+    /// e.g. `http://localhost:3000`
+    base_url: String,
+    /// responses are stored here and reused instead
+    /// of asking the service again
+    cache: Option<PathBuf>,
+    /// cached responses older than this are fetched again
+    max_age: Duration,
+    client: reqwest::blocking::Client,
+}
+
+impl Comlink {
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            cache: None,
+            max_age: DEFAULT_MAX_AGE,
+            client: reqwest::blocking::Client::new(),
+        }
+    }
+
+    /// caches all responses as JSON files in `dir`
+    pub fn with_cache(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cache = Some(dir.into());
+        self
+    }
+
+    /// fetches cached responses again once they are older than `max_age`
+    ///
+    /// an hour by default, [`Duration::ZERO`] never uses the cache
+    pub const fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// the roster of a single player
+    pub fn player(&self, ally_code: &str, units: &Units) -> Result<Roster, ComlinkError> {
+        let ally_code = normalise(ally_code);
+        let player: PlayerResponse = self.fetch(
+            "player",
+            &format!("player-{ally_code}"),
+            &json!({ "allyCode": ally_code }),
+        )?;
+        Ok(player.into_roster(units))
+    }
+
+    /// the rosters of all members of the guild of a player
+    pub fn guild(&self, ally_code: &str, units: &Units) -> Result<Guild, ComlinkError> {
+        let ally_code = normalise(ally_code);
+        let player: PlayerResponse = self.fetch(
+            "player",
+            &format!("player-{ally_code}"),
+            &json!({ "allyCode": ally_code }),
+        )?;
+        let guild_id = player
+            .guild_id
+            .filter(|id| !id.is_empty())
+            .ok_or(ComlinkError::NoGuild(ally_code))?;
+        let guild: GuildResponse = self.fetch(
+            "guild",
+            &format!("guild-{guild_id}"),
+            &json!({ "guildId": guild_id, "includeRecentGuildActivityInfo": false }),
+        )?;
+
+        let members = guild
+            .guild
+            .member
+            .iter()
+            .map(|member| {
+                let player: PlayerResponse = self.fetch(
+                    "player",
+                    &format!("player-{}", member.player_id),
+                    &json!({ "playerId": member.player_id }),
+                )?;
+                Ok(player.into_roster(units))
+            })
+            .collect::<Result<_, ComlinkError>>()?;
+        Ok(Guild { members })
+    }
+
+    fn fetch<T, P>(&self, endpoint: &str, key: &str, payload: &P) -> Result<T, ComlinkError>
+    where
+        T: DeserializeOwned,
+        P: Serialize,
+    {
+        let file = self
+            .cache
+            .as_deref()
+            .map(|dir| dir.join(format!("{key}.json")));
+        if let Some(file) = file.as_deref().filter(|f| self.is_fresh(f)) {
+            log::debug!("using cached {}", file.display());
+            return Ok(serde_json::from_slice(&fs::read(file)?)?);
+        }
+
+        let body = self
+            .client
+            .post(format!("{}/{endpoint}", self.base_url))
+            .json(&json!({ "payload": payload, "enums": false }))
+            .send()?
+            .error_for_status()?
+            .bytes()?;
+        let response = serde_json::from_slice(&body)?;
+        if let Some(file) = file.as_deref() {
+            store(file, &body)?;
+        }
+        Ok(response)
+    }
+
+    /// whether `file` exists and was written less than `max_age` ago
+    fn is_fresh(&self, file: &Path) -> bool {
+        fs::metadata(file)
+            .and_then(|meta| meta.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age < self.max_age)
+    }
+}
+
+fn store(file: &Path, body: &[u8]) -> Result<(), ComlinkError> {
+    if let Some(dir) = file.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(file, body)?;
+    Ok(())
+}
+
+/// ally codes are often written as `123-456-789`
+fn normalise(ally_code: &str) -> String {
+    ally_code.chars().filter(char::is_ascii_digit).collect()
+}
+
+impl PlayerResponse {
+    fn into_roster(self, units: &Units) -> Roster {
+        let stat = |name: &str| {
+            self.profile_stat
+                .iter()
+                .find(|s| s.name_key == name)
+                .and_then(|s| s.value.parse().ok())
+        };
+        let character_gp = stat(CHARACTER_GP);
+        let ship_gp = stat(SHIP_GP);
+
+        let units = self
+            .roster_unit
+            .iter()
+            .filter_map(|unit| {
+                let base_id = unit.base_id();
+                let Some(known) = units.by_base_id(base_id) else {
+                    log::warn!("{}: unknown unit {base_id}", self.name);
+                    return None;
+                };
+                let abilities = |level: u8| {
+                    unit.skill
+                        .iter()
+                        .filter(|skill| skill.level() >= level)
+                        .filter_map(response::Skill::ability)
+                        .collect::<Vec<_>>()
+                };
+                Some(RosterUnit {
+                    id: known.id.clone(),
+                    stars: unit.current_rarity,
+                    // tier 2 is relic 0, below the relic is locked
+                    relic: unit
+                        .relic
+                        .as_ref()
+                        .and_then(|r| r.current_tier.checked_sub(2)),
+                    ship: unit.relic.is_none(),
+                    gp: unit.gp,
+                    omicrons: abilities(OMICRON_LEVEL)
+                        .into_iter()
+                        .filter(|id| known.has_omicron(id))
+                        .collect(),
+                    zetas: abilities(ZETA_LEVEL),
+                    ultimate: unit
+                        .purchased_ability_id
+                        .iter()
+                        .any(|id| id.starts_with("ultimateability")),
+                    speed: None,
+                })
+            })
+            .collect();
+
+        Roster {
+            name: self.name,
+            ally_code: self.ally_code,
+            character_gp,
+            ship_gp,
+            units,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use super::*;
+    use crate::api::mock::serve;
+
+    /// a recorded player response, trimmed to the fields used
+    const PLAYER: &str = r#"{
+        "name": "Tester",
+        "allyCode": "123456789",
+        "guildId": "guild-1",
+        "profileStat": [
+            { "nameKey": "STAT_CHARACTER_GALACTIC_POWER_ACQUIRED_NAME", "value": "5000000" },
+            { "nameKey": "STAT_SHIP_GALACTIC_POWER_ACQUIRED_NAME", "value": "2000000" }
+        ],
+        "rosterUnit": [
+            {
+                "definitionId": "GLREY:SEVEN_STAR",
+                "currentRarity": 7,
+                "relic": { "currentTier": 9 },
+                "gp": 52000,
+                "skill": [
+                    { "id": "basicskill_GLREY", "tier": 5 },
+                    { "id": "leaderskill_GLREY", "tier": 6 },
+                    { "id": "specialskill_GLREY02", "tier": 6 }
+                ],
+                "purchasedAbilityId": [ "ultimateability_GLREY" ]
+            },
+            {
+                "definitionId": "4LOM:SEVEN_STAR",
+                "currentRarity": 6,
+                "relic": { "currentTier": 1 },
+                "skill": [ { "id": "uniqueskill_4LOM01", "tier": 7 } ]
+            },
+            { "definitionId": "CAPITALEXECUTOR:SEVEN_STAR", "currentRarity": 7 },
+            { "definitionId": "NOTAUNIT:SEVEN_STAR", "currentRarity": 7 }
+        ]
+    }"#;
+
+    type Summary = (
+        String,
+        u8,
+        Option<u8>,
+        bool,
+        u64,
+        Vec<String>,
+        Vec<String>,
+        bool,
+    );
+
+    fn summary(roster: &Roster) -> Vec<Summary> {
+        roster
+            .units
+            .iter()
+            .map(|u| {
+                (
+                    u.id.clone(),
+                    u.stars,
+                    u.relic,
+                    u.ship,
+                    u.gp,
+                    u.omicrons.clone(),
+                    u.zetas.clone(),
+                    u.ultimate,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn player_is_mapped_onto_units() {
+        let units = Units::load().expect("units load");
+        let url = serve(1, |endpoint, body| {
+            assert_eq!(endpoint, "/player", "only the player is requested");
+            assert!(body.contains(r#""allyCode":"123456789""#), "{body}");
+            PLAYER.to_owned()
+        });
+
+        let roster = Comlink::new(&url)
+            .player("123-456-789", &units)
+            .expect("a roster");
+
+        assert_eq!(roster.name, "Tester", "name");
+        assert_eq!(roster.character_gp, Some(5_000_000), "character GP");
+        assert_eq!(roster.ship_gp, Some(2_000_000), "ship GP");
+        let s = |s: &[&str]| s.iter().map(|s| (*s).to_owned()).collect::<Vec<_>>();
+        assert_eq!(
+            summary(&roster),
+            [
+                (
+                    "rey".to_owned(),
+                    7,
+                    Some(7),
+                    false,
+                    52_000,
+                    s(&[]),
+                    s(&["l", "s2"]),
+                    true
+                ),
+                (
+                    "4lom".to_owned(),
+                    6,
+                    None,
+                    false,
+                    0,
+                    s(&["u1"]),
+                    s(&["u1"]),
+                    false
+                ),
+                ("exec".to_owned(), 7, None, true, 0, s(&[]), s(&[]), false),
+            ],
+            "unknown units are dropped"
+        );
+    }
+
+    #[test]
+    fn guild_members_are_fetched() {
+        let units = Units::load().expect("units load");
+        let url = serve(3, |endpoint, body| match endpoint {
+            "/player" if body.contains("allyCode") => PLAYER.to_owned(),
+            "/guild" => {
+                assert!(body.contains(r#""guildId":"guild-1""#), "{body}");
+                r#"{ "guild": { "member": [ { "playerId": "p2" } ] } }"#.to_owned()
+            }
+            "/player" if body.contains(r#""playerId":"p2""#) => {
+                r#"{ "name": "Member", "rosterUnit": [] }"#.to_owned()
+            }
+            _ => panic!("unexpected request to {endpoint}: {body}"),
+        });
+
+        let guild = Comlink::new(&url)
+            .guild("123456789", &units)
+            .expect("a guild");
+
+        let names = guild
+            .members
+            .iter()
+            .map(|m| m.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["Member"], "the members listed by the guild");
+    }
+
+    #[test]
+    fn responses_are_cached() {
+        let units = Units::load().expect("units load");
+        let cache = std::env::temp_dir().join(format!("swgoh-tb-comlink-{}", std::process::id()));
+        // answers a single request, the second one must use the cache
+        let url = serve(1, |_, _| PLAYER.to_owned());
+        let comlink = Comlink::new(&url).with_cache(&cache);
+
+        let first = comlink.player("123456789", &units).expect("a roster");
+        let second = comlink
+            .player("123456789", &units)
+            .expect("a cached roster");
+        fs::remove_dir_all(&cache).expect("a removed cache");
+
+        assert_eq!(summary(&first), summary(&second), "same roster");
+    }
+
+    #[test]
+    fn expired_responses_are_fetched_again() {
+        let units = Units::load().expect("units load");
+        let cache =
+            std::env::temp_dir().join(format!("swgoh-tb-comlink-expired-{}", std::process::id()));
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+        let url = serve(2, move |_, _| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            PLAYER.to_owned()
+        });
+        let comlink = Comlink::new(&url)
+            .with_cache(&cache)
+            .with_max_age(Duration::ZERO);
+
+        comlink.player("123456789", &units).expect("a roster");
+        comlink.player("123456789", &units).expect("a fresh roster");
+        fs::remove_dir_all(&cache).expect("a removed cache");
+
+        assert_eq!(
+            requests.load(Ordering::SeqCst),
+            2,
+            "the expired response is requested again"
+        );
+    }
+}
@@ -0,0 +1,101 @@
+//! the parts of the comlink responses needed for a roster
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PlayerResponse {
+    pub name: String,
+    pub ally_code: Option<String>,
+    pub guild_id: Option<String>,
+    #[serde(default)]
+    pub roster_unit: Vec<RosterUnit>,
+    #[serde(default)]
+    pub profile_stat: Vec<ProfileStat>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct RosterUnit {
+    /// e.g. `BOSSK:SEVEN_STAR`
+    pub definition_id: String,
+    pub current_rarity: u8,
+    /// missing for ships
+    pub relic: Option<Relic>,
+    #[serde(default)]
+    pub skill: Vec<Skill>,
+    /// e.g. `ultimateability_GLREY`
+    #[serde(default)]
+    pub purchased_ability_id: Vec<String>,
+    /// only sent by services running a stat calculator
+    #[serde(default)]
+    pub gp: u64,
+}
+
+impl RosterUnit {
+    pub fn base_id(&self) -> &str {
+        self.definition_id
+            .split_once(':')
+            .map_or(self.definition_id.as_str(), |(id, _)| id)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct Skill {
+    /// e.g. `specialskill_BOSSK01`
+    pub id: String,
+    /// counted from 0 at ability level 2
+    pub tier: u8,
+}
+
+impl Skill {
+    pub fn level(&self) -> u8 {
+        self.tier.saturating_add(2)
+    }
+
+    /// the ability in the notation of omicrons, e.g. `s1`
+    pub fn ability(&self) -> Option<String> {
+        let (kind, name) = self.id.split_once('_')?;
+        // numbered by trailing digits, e.g. `01`
+        let prefix = name.trim_end_matches(|c: char| c.is_ascii_digit());
+        let num = name[prefix.len()..].parse::<u8>().unwrap_or(1);
+        match kind {
+            "basicskill" => Some("b".to_owned()),
+            "leaderskill" => Some("l".to_owned()),
+            "specialskill" => Some(format!("s{num}")),
+            "uniqueskill" => Some(format!("u{num}")),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Relic {
+    pub current_tier: u8,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct ProfileStat {
+    pub name_key: String,
+    /// numbers are sent as strings
+    pub value: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct GuildResponse {
+    pub guild: GuildData,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct GuildData {
+    #[serde(default)]
+    pub member: Vec<GuildMember>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GuildMember {
+    pub player_id: String,
+}
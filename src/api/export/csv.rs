@@ -0,0 +1,85 @@
+use crate::PhaseExport;
+
+const HEADER: [&str; 8] = [
+    "phase",
+    "planet",
+    "mission id",
+    "mission name",
+    "relic",
+    "units",
+    "omicrons",
+    "note",
+];
+
+impl PhaseExport {
+    /// one row per mission, lists are joined by `; `
+    pub fn to_csv(&self) -> String {
+        let mut csv = row(&HEADER);
+        for planet in &self.planets {
+            for mission in &planet.missions {
+                csv.push_str(&row(&[
+                    &self.phase.to_string(),
+                    &planet.name,
+                    &mission.id,
+                    &mission.name,
+                    &mission.relic.map(|r| r.to_string()).unwrap_or_default(),
+                    &mission.units.join("; "),
+                    &mission.omicrons.join("; "),
+                    &mission.note.join(" "),
+                ]));
+            }
+        }
+        csv
+    }
+}
+
+fn row(fields: &[&str]) -> String {
+    let mut row = fields
+        .iter()
+        .map(|field| field_escape(field))
+        .collect::<Vec<_>>()
+        .join(",");
+    row.push_str("\r\n");
+    row
+}
+
+/// quotes a field according to RFC 4180 if necessary
+fn field_escape(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_fields_are_not_quoted() {
+        assert_eq!(field_escape("P1DCM1"), "P1DCM1", "nothing to escape");
+        assert_eq!(field_escape(""), "", "empty field");
+    }
+
+    #[test]
+    fn special_fields_are_quoted() {
+        assert_eq!(field_escape("GI, Reva"), "\"GI, Reva\"", "comma");
+        assert_eq!(
+            field_escape("the \"tank\" first"),
+            "\"the \"\"tank\"\" first\"",
+            "quotes are doubled"
+        );
+        assert_eq!(field_escape("a\nb"), "\"a\nb\"", "newline");
+        assert_eq!(field_escape("a\r\nb"), "\"a\r\nb\"", "carriage return");
+    }
+
+    #[test]
+    fn rows_end_with_crlf() {
+        assert_eq!(
+            row(&["a", "b,c", "d\"e"]),
+            "a,\"b,c\",\"d\"\"e\"\r\n",
+            "fields are escaped and joined"
+        );
+    }
+}
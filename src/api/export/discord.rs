@@ -0,0 +1,188 @@
+use crate::{Mission, Phase, Planet, Units};
+
+/// maximum number of characters in a single Discord message
+pub const DISCORD_MESSAGE_LIMIT: usize = 2000;
+
+impl Phase {
+    /// the phase plan as Discord flavoured Markdown, split
+    /// into messages of at most [`DISCORD_MESSAGE_LIMIT`]
+    /// characters
+    ///
+    /// messages are only split between missions, unless a
+    /// single mission is too long on its own
+    pub fn to_discord(&self, num: usize, units: &Units) -> Vec<String> {
+        let mut blocks = vec![format!("# Phase {num}")];
+        for planet in self {
+            blocks.push(planet.discord_header());
+            for mission in &planet.missions {
+                blocks.push(mission.to_discord(units));
+            }
+        }
+        chunk(&blocks, DISCORD_MESSAGE_LIMIT)
+    }
+}
+
+impl Planet {
+    fn discord_header(&self) -> String {
+        let mut header = format!("## {}", self.name);
+        for note in self.notes.iter().flatten() {
+            header.push_str(&format!("\n-# {note}"));
+        }
+        header
+    }
+}
+
+impl Mission {
+    /// a single mission as Discord flavoured Markdown
+    pub fn to_discord(&self, units: &Units) -> String {
+        let export = self.export(units);
+
+        let mut lines = vec![format!(
+            "**{}** - {} ({})",
+            export.id,
+            export.name,
+            export.requirement()
+        )];
+        lines.push(format!("> {}", export.units.join(", ")));
+        for omicron in &export.omicrons {
+            lines.push(format!("> :warning: Omicron: {omicron}"));
+        }
+        for note in &export.note {
+            lines.push(format!("> {note}"));
+        }
+        if let Some(videos) = &self.videos {
+            // wrapping links in <> prevents Discord from embedding them
+            let videos = videos
+                .iter()
+                .map(|video| format!("[{}](<{}>)", video.source, video.url))
+                .collect::<Vec<_>>()
+                .join(", ");
+            lines.push(format!("> Videos: {videos}"));
+        }
+        lines.join("\n")
+    }
+}
+
+/// joins `blocks` by newlines into as few chunks as possible
+/// with at most `limit` characters each
+fn chunk(blocks: &[String], limit: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+
+    for block in blocks {
+        let pieces = if block.chars().count() > limit {
+            split(block, limit)
+        } else {
+            vec![block.clone()]
+        };
+
+        for piece in pieces {
+            if !current.is_empty() && current.chars().count() + 1 + piece.chars().count() > limit {
+                chunks.push(std::mem::take(&mut current));
+            }
+            if !current.is_empty() {
+                current.push('\n');
+            }
+            current.push_str(&piece);
+        }
+    }
+
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+    chunks
+}
+
+/// splits a block which is too long for a single message
+/// into its lines, keeping code blocks together and breaking
+/// them apart only if necessary
+fn split(block: &str, limit: usize) -> Vec<String> {
+    let mut pieces = Vec::new();
+    let mut code: Option<String> = None;
+    for line in block.lines() {
+        // an odd number of fences opens or closes a code block
+        let fence = line.matches("```").count() % 2 == 1;
+        match (&mut code, fence) {
+            (Some(code), false) => {
+                code.push('\n');
+                code.push_str(line);
+            }
+            (Some(_), true) => {
+                let mut closed = code.take().unwrap_or_default();
+                closed.push('\n');
+                closed.push_str(line);
+                pieces.extend(break_apart(&closed, limit));
+            }
+            (None, true) => code = Some(line.to_owned()),
+            (None, false) => pieces.extend(break_apart(line, limit)),
+        }
+    }
+    if let Some(code) = code {
+        pieces.extend(break_apart(&code, limit));
+    }
+    pieces
+}
+
+/// breaks `s` into pieces of at most `limit` characters
+fn break_apart(s: &str, limit: usize) -> Vec<String> {
+    let chars = s.chars().collect::<Vec<_>>();
+    chars
+        .chunks(limit)
+        .map(|piece| piece.iter().collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMIT: usize = DISCORD_MESSAGE_LIMIT;
+
+    fn lengths(chunks: &[String]) -> Vec<usize> {
+        chunks.iter().map(|c| c.chars().count()).collect()
+    }
+
+    #[test]
+    fn exactly_the_limit_fits_one_message() {
+        let blocks = ["a".repeat(LIMIT - 11), "b".repeat(10)];
+        let chunks = chunk(&blocks, LIMIT);
+        assert_eq!(lengths(&chunks), [LIMIT], "two blocks and a newline");
+    }
+
+    #[test]
+    fn one_more_character_starts_a_new_message() {
+        let blocks = ["a".repeat(LIMIT - 10), "b".repeat(10)];
+        let chunks = chunk(&blocks, LIMIT);
+        assert_eq!(
+            chunks,
+            [blocks[0].clone(), blocks[1].clone()],
+            "blocks are not split between messages"
+        );
+    }
+
+    #[test]
+    fn oversized_lines_are_broken_apart() {
+        let line = "ä".repeat(LIMIT * 2 + 1);
+        let blocks = ["# Phase 1".to_owned(), format!("short\n{line}")];
+        let chunks = chunk(&blocks, LIMIT);
+        assert_eq!(
+            lengths(&chunks),
+            [15, LIMIT, LIMIT, 1],
+            "counted in characters, not bytes"
+        );
+        assert_eq!(chunks[0], "# Phase 1\nshort", "lines before stay whole");
+        assert_eq!(chunks[1..].concat(), line, "nothing is lost");
+    }
+
+    #[test]
+    fn code_blocks_are_not_split() {
+        let code = format!("```\n{}\n```", vec!["c".repeat(200); 3].join("\n"));
+        let blocks = [format!("{}\n{code}", "a".repeat(1500))];
+        let chunks = chunk(&blocks, LIMIT);
+        assert_eq!(
+            chunks,
+            ["a".repeat(1500), code],
+            "the code block moves to the next message as a whole"
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{MissionExport, PhaseExport, PlanetExport};
+
+impl PhaseExport {
+    pub fn to_markdown(&self) -> String {
+        let mut md = format!("# Phase {}\n", self.phase);
+        for planet in &self.planets {
+            md.push('\n');
+            md.push_str(&planet.to_markdown());
+        }
+        md
+    }
+}
+
+impl PlanetExport {
+    pub fn to_markdown(&self) -> String {
+        let mut md = format!("## {}\n\n", self.name);
+        for note in &self.notes {
+            md.push_str(note);
+            md.push_str("\n\n");
+        }
+        md.push_str("| ID | Mission | Requirement | Team | Omicrons | Note |\n");
+        md.push_str("|----|---------|-------------|------|----------|------|\n");
+        for mission in &self.missions {
+            md.push_str(&mission.to_markdown_row());
+            md.push('\n');
+        }
+        md
+    }
+}
+
+impl MissionExport {
+    /// a single row of the planet table
+    fn to_markdown_row(&self) -> String {
+        format!(
+            "| {} | {} | {} | {} | {} | {} |",
+            cell(&self.id),
+            cell(&self.name),
+            cell(&self.requirement()),
+            cell(&self.units.join(", ")),
+            cell(&self.omicrons.join("<br>")),
+            cell(&self.note.join("<br>")),
+        )
+    }
+}
+
+/// escapes characters which would break a table cell
+fn cell(s: &str) -> String {
+    s.replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cells_escape_pipes_and_newlines() {
+        assert_eq!(cell("GI"), "GI", "nothing to escape");
+        assert_eq!(cell("a | b"), "a \\| b", "pipe");
+        assert_eq!(cell("a\nb"), "a<br>b", "newline");
+        assert_eq!(cell("a\r\nb"), "a<br>b", "windows newline");
+        assert_eq!(cell("a|\n|b"), "a\\|<br>\\|b", "both");
+    }
+}
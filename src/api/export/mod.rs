@@ -0,0 +1,131 @@
+mod csv;
+mod discord;
+mod markdown;
+
+pub use discord::DISCORD_MESSAGE_LIMIT;
+
+use serde::Serialize;
+
+use crate::{Mission, Omicrons, Phase, Planet, Units};
+
+/// a phase plan with all unit IDs resolved
+/// to their display names
+#[derive(Debug, Serialize, Clone)]
+pub struct PhaseExport {
+    /// number of the phase, starting at 1
+    pub phase: usize,
+    pub planets: Vec<PlanetExport>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PlanetExport {
+    pub name: String,
+    pub notes: Vec<String>,
+    pub missions: Vec<MissionExport>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct MissionExport {
+    pub id: String,
+    pub name: String,
+    /// `None` for fleet missions, which
+    /// only require 7 stars
+    pub relic: Option<u8>,
+    /// unit names, unavailable slots are skipped
+    pub units: Vec<String>,
+    /// human readable omicrons, e.g.
+    /// `Marrok: Unique 1 "Ability Name"`
+    pub omicrons: Vec<String>,
+    pub note: Vec<String>,
+}
+
+impl Phase {
+    /// resolves this phase into an exportable plan,
+    /// `num` is the number of the phase starting at 1
+    pub fn export(&self, num: usize, units: &Units) -> PhaseExport {
+        PhaseExport {
+            phase: num,
+            planets: self.iter().map(|planet| planet.export(units)).collect(),
+        }
+    }
+
+    /// the phase plan as Markdown tables, one per planet
+    pub fn to_markdown(&self, num: usize, units: &Units) -> String {
+        self.export(num, units).to_markdown()
+    }
+
+    /// the phase plan as CSV, one row per mission
+    pub fn to_csv(&self, num: usize, units: &Units) -> String {
+        self.export(num, units).to_csv()
+    }
+
+    /// the phase plan as pretty printed JSON
+    pub fn to_json(&self, num: usize, units: &Units) -> serde_json::Result<String> {
+        self.export(num, units).to_json()
+    }
+}
+
+impl Planet {
+    pub fn export(&self, units: &Units) -> PlanetExport {
+        PlanetExport {
+            name: self.name.clone(),
+            notes: self.notes.clone().unwrap_or_default(),
+            missions: self
+                .missions
+                .iter()
+                .map(|mission| mission.export(units))
+                .collect(),
+        }
+    }
+}
+
+impl Mission {
+    pub fn export(&self, units: &Units) -> MissionExport {
+        MissionExport {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            relic: self.relic(),
+            units: self
+                .team
+                .iter()
+                .filter(|slot| !slot.is_unavailable())
+                .map(|slot| slot.name(units))
+                .collect(),
+            omicrons: self
+                .omicrons
+                .as_deref()
+                .map(|omicrons| omicron_names(omicrons, units))
+                .unwrap_or_default(),
+            note: self.note.clone(),
+        }
+    }
+}
+
+impl PhaseExport {
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+impl MissionExport {
+    /// the relic requirement in the same format
+    /// as shown on the page
+    pub fn requirement(&self) -> String {
+        match self.relic {
+            Some(relic) => format!("Relic: {relic}+"),
+            None => "7* Stars".to_owned(),
+        }
+    }
+}
+
+fn omicron_names(omicrons: &[Omicrons], units: &Units) -> Vec<String> {
+    let mut names = Vec::new();
+    for omicron in omicrons {
+        let unit = units.get(&omicron.unit);
+        for omi in &omicron.omis {
+            let ability = unit.get_omicron(&omi.to_id());
+            names.push(format!("{}: {} \"{}\"", unit.name, omi, ability.name));
+        }
+    }
+    names
+}
@@ -0,0 +1,69 @@
+use std::fmt::Display;
+
+use serde::de::DeserializeOwned;
+
+/// a data file that could not be loaded
+#[derive(Debug, Clone)]
+pub struct LoadError {
+    /// e.g. `Phase3.toml`
+    pub file: String,
+    /// line and column of the problem, both starting at 1
+    pub position: Option<(usize, usize)>,
+    /// path of the offending key, e.g. `Dark.mission[2].relic`
+    pub key: Option<String>,
+    pub message: String,
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.file)?;
+        if let Some((line, column)) = self.position {
+            write!(f, ":{line}:{column}")?;
+        }
+        if let Some(key) = &self.key {
+            write!(f, " at `{key}`")?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// deserializes the TOML `bytes` of `file`
+pub(crate) fn parse<T: DeserializeOwned>(file: &str, bytes: &[u8]) -> Result<T, LoadError> {
+    let error = |message: String| LoadError {
+        file: file.to_owned(),
+        position: None,
+        key: None,
+        message,
+    };
+    let s = std::str::from_utf8(bytes).map_err(|err| error(err.to_string()))?;
+    let position = |err: &toml::de::Error| err.span().map(|span| line_column(s, span.start));
+
+    let deserializer = toml::Deserializer::parse(s).map_err(|err| LoadError {
+        position: position(&err),
+        ..error(err.message().to_owned())
+    })?;
+    serde_path_to_error::deserialize(deserializer).map_err(|err| {
+        let key = err.path().to_string();
+        let inner = err.into_inner();
+        LoadError {
+            position: position(&inner),
+            key: (key != ".").then_some(key),
+            ..error(inner.message().to_owned())
+        }
+    })
+}
+
+fn line_column(s: &str, offset: usize) -> (usize, usize) {
+    let before = &s[..offset.min(s.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rsplit('\n')
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .count()
+        + 1;
+    (line, column)
+}
@@ -0,0 +1,50 @@
+//! a minimal HTTP server for testing the native clients
+
+use std::{
+    io::{BufRead as _, BufReader, Read as _, Write as _},
+    net::TcpListener,
+    thread,
+};
+
+/// serves `count` requests on a random port, answering with
+/// `respond(endpoint, body)`, returns the base URL
+pub(crate) fn serve<F>(count: usize, respond: F) -> String
+where
+    F: Fn(&str, &str) -> String + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").expect("a free port");
+    let url = format!("http://{}", listener.local_addr().expect("a bound port"));
+    thread::spawn(move || {
+        for stream in listener.incoming().take(count) {
+            let mut stream = stream.expect("a connection");
+            let mut reader = BufReader::new(&mut stream);
+            let mut line = String::new();
+            reader.read_line(&mut line).expect("a request line");
+            let endpoint = line.split(' ').nth(1).unwrap_or_default().to_owned();
+            let mut length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).expect("a header");
+                if header.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = header.split_once(':')
+                    && name.eq_ignore_ascii_case("content-length")
+                {
+                    length = value.trim().parse().expect("a numeric length");
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).expect("the body");
+            let response = respond(&endpoint, &String::from_utf8_lossy(&body));
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n\
+                 content-length: {}\r\nconnection: close\r\n\r\n{response}",
+                response.len()
+            )
+            .expect("a written response");
+        }
+    });
+    url
+}
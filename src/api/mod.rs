@@ -1,5 +1,26 @@
+mod assignments;
+pub mod bot;
+#[cfg(all(feature = "comlink", not(target_arch = "wasm32")))]
+mod comlink;
+mod export;
+mod load;
+#[cfg(all(
+    test,
+    any(feature = "comlink", feature = "webhook"),
+    not(target_arch = "wasm32")
+))]
+mod mock;
+mod roster;
 mod teams;
+mod tracker;
 mod units;
 
+pub use assignments::*;
+#[cfg(all(feature = "comlink", not(target_arch = "wasm32")))]
+pub use comlink::*;
+pub use export::*;
+pub use load::LoadError;
+pub use roster::*;
 pub use teams::*;
+pub use tracker::*;
 pub use units::*;
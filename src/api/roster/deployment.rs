@@ -0,0 +1,220 @@
+use std::collections::HashSet;
+
+use crate::{Guild, OperationsPlan, Phase, Plan, Planet};
+
+/// how much GP the guild should deploy to each planet of a phase
+#[derive(Debug, Clone)]
+pub struct Deployment {
+    pub planets: Vec<PlanetDeployment>,
+    /// deployable GP of the whole guild
+    pub available: u64,
+    /// GP not needed to reach any further star
+    pub leftover: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct PlanetDeployment {
+    pub name: String,
+    /// territory points the planet already has, e.g.
+    /// from combat missions or an earlier phase
+    pub points: u64,
+    /// GP to deploy to this planet
+    pub deploy: u64,
+    /// projected number of stars
+    pub stars: u8,
+    /// `None` if the star thresholds of this planet are unknown
+    pub thresholds: Option<[u64; 3]>,
+}
+
+impl Deployment {
+    pub fn planet(&self, name: &str) -> Option<&PlanetDeployment> {
+        self.planets.iter().find(|p| p.name == name)
+    }
+}
+
+impl PlanetDeployment {
+    /// territory points still needed for the next star,
+    /// `None` with three stars or unknown thresholds
+    fn missing(&self) -> Option<u64> {
+        let next = *self.thresholds?.get(usize::from(self.stars))?;
+        Some(next.saturating_sub(self.points + self.deploy))
+    }
+}
+
+impl Phase {
+    /// recommends how much GP to deploy to each planet to get as
+    /// many stars as possible
+    ///
+    /// the points of all planned missions count towards the stars,
+    /// units used in the `plans` of the members or to fill
+    /// `operations` cannot be deployed. Stars are bought greedily,
+    /// always taking the cheapest next star of any planet, the
+    /// remaining GP is deployed to the planet closest to its next
+    /// star
+    pub fn deployment(
+        &self,
+        guild: &Guild,
+        plans: &[Plan],
+        operations: Option<&OperationsPlan>,
+    ) -> Deployment {
+        let available = deployable(guild, plans, operations);
+        let mut planets = self
+            .iter()
+            .map(|planet| PlanetDeployment {
+                name: planet.name.clone(),
+                points: mission_points(planet, plans, 1.0),
+                deploy: 0,
+                stars: 0,
+                thresholds: planet.thresholds,
+            })
+            .collect::<Vec<_>>();
+
+        let leftover = allocate(&mut planets, available);
+        let leftover = preload(&mut planets, leftover);
+        Deployment {
+            planets,
+            available,
+            leftover,
+        }
+    }
+}
+
+/// expected territory points of all planned missions on `planet`
+pub(crate) fn mission_points(planet: &Planet, plans: &[Plan], success_rate: f64) -> u64 {
+    let points = plans
+        .iter()
+        .flat_map(|plan| &plan.picks)
+        .filter(|pick| planet.missions.iter().any(|m| m.id == pick.mission.id))
+        .map(|pick| pick.points)
+        .sum::<u64>();
+    #[expect(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "territory points are far below 2^52 and never negative"
+    )]
+    let expected = (points as f64 * success_rate).round() as u64;
+    expected
+}
+
+/// GP of the guild not used by the `plans` or `operations`
+pub(crate) fn deployable(
+    guild: &Guild,
+    plans: &[Plan],
+    operations: Option<&OperationsPlan>,
+) -> u64 {
+    let mut committed: HashSet<(&str, String)> = HashSet::new();
+    for plan in plans {
+        for pick in &plan.picks {
+            for id in &pick.units {
+                committed.insert((&plan.player, id.clone()));
+            }
+        }
+    }
+    for slot in operations.iter().flat_map(|o| &o.slots) {
+        if let Some(member) = &slot.member {
+            committed.insert((member, slot.unit.to_ascii_lowercase()));
+        }
+    }
+
+    guild
+        .members
+        .iter()
+        .map(|member| {
+            let used = member
+                .units
+                .iter()
+                .filter(|u| committed.contains(&(member.name.as_str(), u.id.to_ascii_lowercase())))
+                .map(|u| u.gp)
+                .sum::<u64>();
+            member.gp().saturating_sub(used)
+        })
+        .sum()
+}
+
+/// buys the cheapest next star of any planet until `gp`
+/// runs out, returns the GP left
+pub(crate) fn allocate(planets: &mut [PlanetDeployment], mut gp: u64) -> u64 {
+    loop {
+        let cheapest = planets
+            .iter_mut()
+            .filter_map(|p| Some((p.missing()?, p)))
+            .min_by_key(|(cost, _)| *cost);
+        match cheapest {
+            Some((cost, planet)) if cost <= gp => {
+                gp -= cost;
+                planet.deploy += cost;
+                planet.stars += 1;
+            }
+            _ => return gp,
+        }
+    }
+}
+
+/// deploys `gp` to the planet closest to its next star,
+/// returns the GP left if no planet can take it
+pub(crate) fn preload(planets: &mut [PlanetDeployment], gp: u64) -> u64 {
+    match planets
+        .iter_mut()
+        .filter_map(|p| Some((p.missing()?, p)))
+        .min_by_key(|(cost, _)| *cost)
+    {
+        Some((_, planet)) => {
+            planet.deploy += gp;
+            0
+        }
+        None => gp,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Pick, api::teams::test_phase};
+
+    const PHASE: &str = r#"
+Mixed = { name = "Corellia", mission = [] }
+Light = { name = "Coruscant", mission = [] }
+
+[Dark]
+name = "Mustafar"
+thresholds = [ 100, 200, 300 ]
+
+[[Dark.mission]]
+id = "P1DCM1"
+name = "Combat Mission"
+team = [ "gi", "", "", "", "" ]
+note = []
+"#;
+
+    #[test]
+    fn planned_mission_points_count_towards_stars() {
+        let phase = test_phase(PHASE);
+        let plan = Plan {
+            player: "A".to_owned(),
+            phase: 1,
+            picks: vec![Pick {
+                mission: phase.dark.missions[0].clone(),
+                units: vec!["gi".to_owned()],
+                points: 150,
+            }],
+            impossible: Vec::new(),
+            points: 150,
+        };
+        let guild = Guild::from_toml(
+            r#"
+            [[Member]]
+            name = "A"
+            character_gp = 60
+            "#,
+        )
+        .expect("valid guild");
+
+        let deployment = phase.deployment(&guild, &[plan], None);
+        let mustafar = deployment.planet("Mustafar").expect("planned planet");
+        assert_eq!(mustafar.points, 150, "points of the planned mission");
+        assert_eq!(mustafar.stars, 2, "one star from the mission, one bought");
+        assert_eq!(mustafar.deploy, 60, "50 for the second star, 10 preloaded");
+        assert_eq!(deployment.leftover, 0, "all GP is deployed");
+    }
+}
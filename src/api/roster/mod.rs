@@ -0,0 +1,348 @@
+mod deployment;
+mod operations;
+mod optimiser;
+mod simulation;
+
+pub use deployment::*;
+pub use operations::*;
+pub use optimiser::*;
+pub use simulation::*;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Mission, Requirement};
+
+/// rosters of all members of a guild
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Guild {
+    #[serde(rename = "Member", default)]
+    pub members: Vec<Roster>,
+}
+
+/// the units of a single player
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Roster {
+    /// in-game name of the player
+    pub name: String,
+    pub ally_code: Option<String>,
+    /// total character GP, if unknown the GP
+    /// of all characters is summed up
+    pub character_gp: Option<u64>,
+    /// total ship GP, if unknown the GP of
+    /// all ships is summed up
+    pub ship_gp: Option<u64>,
+    #[serde(rename = "Unit", default)]
+    pub units: Vec<RosterUnit>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RosterUnit {
+    /// unit ID as used in `Units.toml`
+    pub id: String,
+    #[serde(default)]
+    pub stars: u8,
+    /// `None` if the unit is not yet at Gear 13 or a ship
+    pub relic: Option<u8>,
+    #[serde(default)]
+    pub ship: bool,
+    /// galactic power of the unit
+    #[serde(default)]
+    pub gp: u64,
+    /// IDs of the applied omicron abilities, e.g. `u1`
+    #[serde(default)]
+    pub omicrons: Vec<String>,
+    /// IDs of the applied zeta abilities, e.g. `l`
+    #[serde(default)]
+    pub zetas: Vec<String>,
+    /// whether the ultimate ability is unlocked
+    #[serde(default)]
+    pub ultimate: bool,
+    /// `None` if unknown
+    pub speed: Option<u32>,
+}
+
+impl Guild {
+    /// storage key of the guild rosters
+    pub const KEY: &str = "guild";
+
+    pub fn from_toml(s: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(s)
+    }
+
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string(self)
+    }
+
+    pub fn member(&self, name: &str) -> Option<&Roster> {
+        self.members.iter().find(|m| m.name == name)
+    }
+}
+
+impl RosterUnit {
+    /// whether this unit satisfies a relic requirement,
+    /// ships only need 7 stars instead
+    pub fn meets(&self, relic: u8) -> bool {
+        if self.ship {
+            self.stars >= 7
+        } else {
+            self.relic.is_some_and(|own| own >= relic)
+        }
+    }
+
+    /// whether this unit has the zetas, ultimate and speed
+    /// of `requirement`
+    ///
+    /// zetas and the ultimate are part of every imported
+    /// roster, an unknown speed is assumed to suffice
+    pub fn fulfils(&self, requirement: &Requirement) -> bool {
+        let zetas = requirement
+            .zetas
+            .iter()
+            .flatten()
+            .all(|zeta| self.zetas.contains(&zeta.to_id()));
+        let speed = match (requirement.speed, self.speed) {
+            (Some(required), Some(speed)) => speed >= required,
+            _ => true,
+        };
+        zetas && (self.ultimate || !requirement.ultimate) && speed
+    }
+}
+
+impl Roster {
+    pub fn get(&self, id: &str) -> Option<&RosterUnit> {
+        self.units.iter().find(|u| u.id.eq_ignore_ascii_case(id))
+    }
+
+    /// total character and ship GP of this player
+    pub fn gp(&self) -> u64 {
+        let sum = |ship: bool| {
+            self.units
+                .iter()
+                .filter(|u| u.ship == ship)
+                .map(|u| u.gp)
+                .sum::<u64>()
+        };
+        self.character_gp.unwrap_or_else(|| sum(false)) + self.ship_gp.unwrap_or_else(|| sum(true))
+    }
+
+    /// whether this player owns all units of `mission` at
+    /// the required relic (or 7 stars for fleets) with all
+    /// required omicrons and per unit requirements
+    ///
+    /// placeholder and unavailable slots are ignored
+    pub fn can_play(&self, mission: &Mission) -> bool {
+        self.team(mission).is_some()
+    }
+
+    /// lowercase IDs of the units this player would use for
+    /// `mission`, one different unit per slot with the preferred
+    /// options first, `None` if a slot cannot be filled
+    ///
+    /// placeholder and unavailable slots are ignored
+    pub fn team(&self, mission: &Mission) -> Option<Vec<String>> {
+        let slots = mission
+            .team
+            .iter()
+            .enumerate()
+            .filter(|(_, slot)| !slot.is_open())
+            .map(|(idx, slot)| {
+                slot.options()
+                    .iter()
+                    .filter(|id| self.ready(mission, idx, id))
+                    .map(|id| id.to_ascii_lowercase())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let mut team = Vec::new();
+        assign(&slots, &mut team).then_some(team)
+    }
+
+    /// whether the unit `id` is owned and meets the requirements
+    /// of `mission` for the slot at `slot`, including the omicrons
+    /// of the unit if it is chosen
+    fn ready(&self, mission: &Mission, slot: usize, id: &str) -> bool {
+        let requirement = mission.requirement(slot);
+        let mut omicrons = mission
+            .omicrons
+            .iter()
+            .flatten()
+            .filter(|omicrons| omicrons.unit.eq_ignore_ascii_case(id))
+            .flat_map(|omicrons| &omicrons.omis);
+        self.get(id).is_some_and(|unit| {
+            // a character mission without any relic requirement is a
+            // data error, it is treated as unplayable instead of free
+            let ready = if mission.is_fleet() {
+                unit.stars >= 7
+            } else {
+                requirement
+                    .and_then(|r| r.relic)
+                    .or(mission.relic())
+                    .is_some_and(|relic| unit.meets(relic))
+            };
+            ready
+                && requirement.is_none_or(|r| unit.fulfils(r))
+                && omicrons.all(|omi| unit.omicrons.contains(&omi.to_id()))
+        })
+    }
+}
+
+/// picks a unit for every slot without using one twice,
+/// backtracking if a later slot cannot be filled
+fn assign(slots: &[Vec<String>], team: &mut Vec<String>) -> bool {
+    let Some((options, rest)) = slots.split_first() else {
+        return true;
+    };
+    for id in options {
+        if team.contains(id) {
+            continue;
+        }
+        team.push(id.clone());
+        if assign(rest, team) {
+            return true;
+        }
+        team.pop();
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Units;
+
+    fn mission(s: &str) -> Mission {
+        toml::from_str(&format!(
+            "id = \"P1DCM1\"\nname = \"Combat Mission\"\nnote = []\nrelic = 5\n{s}"
+        ))
+        .expect("valid mission")
+    }
+
+    fn roster(s: &str) -> Roster {
+        toml::from_str(&format!("name = \"A\"\n{s}")).expect("valid roster")
+    }
+
+    #[test]
+    fn requirements_apply_to_the_unit_filling_their_slot() {
+        let mission = mission(
+            r#"
+            team = [ "gi", [ "7sis", "9sis" ], "", "", "" ]
+            requirements = [ { slot = 1, zetas = [ "l" ] } ]
+            "#,
+        );
+        let zeta = roster(
+            r#"Unit = [
+                { id = "gi", relic = 5 },
+                { id = "7sis", relic = 5 },
+                { id = "9sis", relic = 5, zetas = [ "l" ] },
+            ]"#,
+        );
+        assert_eq!(
+            zeta.team(&mission),
+            Some(vec!["gi".to_owned(), "9sis".to_owned()]),
+            "only the Ninth Sister has the zeta"
+        );
+
+        let none = roster(
+            r#"Unit = [
+                { id = "gi", relic = 5, zetas = [ "l" ] },
+                { id = "7sis", relic = 5 },
+            ]"#,
+        );
+        assert_eq!(
+            none.team(&mission),
+            None,
+            "no option of slot 1 has the zeta"
+        );
+    }
+
+    #[test]
+    fn omicrons_only_count_for_chosen_units() {
+        let mission = mission(
+            r#"
+            team = [ "gi", [ "marrok", "7sis" ], "", "", "" ]
+            omicrons = [ { unit = "marrok", omis = [ "u1" ] } ]
+            "#,
+        );
+        let sister = roster(
+            r#"Unit = [
+                { id = "gi", relic = 5 },
+                { id = "marrok", relic = 5 },
+                { id = "7sis", relic = 5 },
+            ]"#,
+        );
+        assert_eq!(
+            sister.team(&mission),
+            Some(vec!["gi".to_owned(), "7sis".to_owned()]),
+            "Marrok lacks the omicron, so the Seventh Sister fills his slot"
+        );
+
+        let marrok = roster(
+            r#"Unit = [
+                { id = "gi", relic = 5 },
+                { id = "marrok", relic = 5, omicrons = [ "u1" ] },
+            ]"#,
+        );
+        assert!(marrok.can_play(&mission), "Marrok has the omicron");
+
+        let neither =
+            roster(r#"Unit = [ { id = "gi", relic = 5 }, { id = "marrok", relic = 5 } ]"#);
+        assert!(
+            !neither.can_play(&mission),
+            "no option of the slot is ready"
+        );
+    }
+
+    #[test]
+    fn slots_are_filled_by_backtracking() {
+        let slots = [
+            vec!["7sis".to_owned(), "9sis".to_owned()],
+            vec!["7sis".to_owned()],
+        ];
+        let mut team = Vec::new();
+        assert!(assign(&slots, &mut team), "both slots can be filled");
+        assert_eq!(
+            team,
+            ["9sis", "7sis"],
+            "the first slot gives up its preferred option"
+        );
+
+        let slots = [vec!["7sis".to_owned()], vec!["7sis".to_owned()]];
+        let mut team = Vec::new();
+        assert!(!assign(&slots, &mut team), "a unit is only used once");
+        assert!(team.is_empty(), "nothing is left over: {team:?}");
+    }
+
+    #[test]
+    fn tag_slots_are_filled_from_their_units() {
+        let units = Units::load().expect("units load");
+        let phase = crate::Phase::parse(
+            "Phase1.toml",
+            br#"
+            Dark = { name = "Mustafar", relic = 5, mission = [
+                { id = "P1DCM1", name = "Combat Mission", note = [], team = [
+                    "gi", { tag = "inquisitorius" }, { tag = "inquisitorius" }, "", ""
+                ] },
+            ] }
+            Mixed = { name = "Corellia", relic = 5, mission = [] }
+            Light = { name = "Coruscant", relic = 5, mission = [] }
+            "#,
+            &units,
+        )
+        .expect("valid phase");
+        let roster = roster(
+            r#"Unit = [
+                { id = "gi", relic = 5 },
+                { id = "5bro", relic = 5 },
+                { id = "9sis", relic = 5 },
+            ]"#,
+        );
+        let team = roster
+            .team(&phase.dark.missions[0])
+            .expect("two inquisitors");
+        assert_eq!(team.len(), 3, "{team:?}");
+        assert!(
+            team.contains(&"5bro".to_owned()) && team.contains(&"9sis".to_owned()),
+            "both inquisitors are used: {team:?}"
+        );
+    }
+}
@@ -0,0 +1,252 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{Guild, Operations, Phase, Plan, Slot, default_points};
+
+/// who should fill which operation slot of a phase
+#[derive(Debug, Clone)]
+pub struct OperationsPlan {
+    pub slots: Vec<SlotFill>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SlotFill {
+    pub planet: String,
+    pub operation: u8,
+    pub unit: String,
+    /// suggested member to fill this slot, `None` if
+    /// nobody in the guild can fill it
+    pub member: Option<String>,
+    /// the unit is also part of a combat mission team of this
+    /// phase, so filling the slot might cost a mission
+    pub combat: bool,
+    /// the suggested member needs this unit for their own plan
+    pub conflict: bool,
+}
+
+impl OperationsPlan {
+    pub fn filled(&self, planet: &str) -> (usize, usize) {
+        let slots = self.slots.iter().filter(|s| s.planet == planet);
+        let total = slots.clone().count();
+        (slots.filter(|s| s.member.is_some()).count(), total)
+    }
+}
+
+impl Operations {
+    /// suggests which member of `guild` should fill each slot
+    ///
+    /// each member can use a unit only once per phase. Members
+    /// who do not need a unit for the missions of their own
+    /// [`Plan`] are preferred, afterwards the member with the
+    /// fewest filled slots is chosen to spread the load
+    pub fn fill(&self, phase: &Phase, num: usize, guild: &Guild) -> OperationsPlan {
+        let plans = guild.plans(phase, num, default_points);
+        let combat = phase
+            .iter()
+            .flat_map(|planet| &planet.missions)
+            .flat_map(|mission| &mission.team)
+            .flat_map(Slot::options)
+            .map(|id| id.to_ascii_lowercase())
+            .collect::<HashSet<_>>();
+
+        let mut slots = Vec::new();
+        for planet in self.iter() {
+            for operation in &planet.operations {
+                for unit in &operation.slots {
+                    let candidates = guild
+                        .members
+                        .iter()
+                        .filter(|m| m.get(unit).is_some_and(|u| u.meets(planet.relic)))
+                        .count();
+                    slots.push((candidates, slots.len(), planet, operation.id, unit));
+                }
+            }
+        }
+        // the rarest units are handed out first
+        slots.sort_by_key(|(candidates, ..)| *candidates);
+
+        let mut used: HashSet<(&str, String)> = HashSet::new();
+        let mut load: HashMap<&str, usize> = HashMap::new();
+        let mut fills = Vec::new();
+
+        for (_, order, planet, operation, unit) in slots {
+            let id = unit.to_ascii_lowercase();
+            let best = guild
+                .members
+                .iter()
+                .filter(|m| m.get(unit).is_some_and(|u| u.meets(planet.relic)))
+                .filter(|m| !used.contains(&(m.name.as_str(), id.clone())))
+                .map(|m| {
+                    let conflict = plan_uses(&plans, &m.name, &id);
+                    let filled = load.get(m.name.as_str()).copied().unwrap_or_default();
+                    (conflict, filled, m)
+                })
+                .min_by_key(|(conflict, filled, _)| (*conflict, *filled));
+
+            let (member, conflict) = match best {
+                Some((conflict, _, m)) => {
+                    used.insert((m.name.as_str(), id.clone()));
+                    *load.entry(m.name.as_str()).or_default() += 1;
+                    (Some(m.name.clone()), conflict)
+                }
+                None => (None, false),
+            };
+
+            fills.push((
+                order,
+                SlotFill {
+                    planet: planet.name.clone(),
+                    operation,
+                    unit: unit.clone(),
+                    member,
+                    combat: combat.contains(&id),
+                    conflict,
+                },
+            ));
+        }
+
+        // back to the order of the data file
+        fills.sort_by_key(|(order, _)| *order);
+        OperationsPlan {
+            slots: fills.into_iter().map(|(_, fill)| fill).collect(),
+        }
+    }
+}
+
+fn plan_uses(plans: &[Plan], player: &str, unit: &str) -> bool {
+    plans
+        .iter()
+        .filter(|plan| plan.player == player)
+        .flat_map(|plan| &plan.picks)
+        .flat_map(|pick| &pick.units)
+        .any(|id| id.eq_ignore_ascii_case(unit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::teams::test_phase;
+
+    const PHASE: &str = r#"
+Mixed = { name = "Corellia", mission = [] }
+Light = { name = "Coruscant", mission = [] }
+
+[Dark]
+name = "Mustafar"
+relic = 5
+
+[[Dark.mission]]
+id = "P1DCM1"
+name = "Combat Mission"
+team = [ "gi", "", "", "", "" ]
+note = []
+relic = 7
+"#;
+
+    fn fill(slots: &str, guild: &str) -> Vec<(String, Option<String>, bool, bool)> {
+        let phase = test_phase(PHASE);
+        let operations: Operations = toml::from_str(&format!(
+            r#"
+            Dark = {{ name = "Mustafar", relic = 5, operation = [ {{ id = 1, slots = {slots} }} ] }}
+            Mixed = {{ name = "Corellia", relic = 5 }}
+            Light = {{ name = "Coruscant", relic = 5 }}
+            "#
+        ))
+        .expect("valid operations");
+        let guild = Guild::from_toml(guild).expect("valid guild");
+        operations
+            .fill(&phase, 1, &guild)
+            .slots
+            .into_iter()
+            .map(|slot| (slot.unit, slot.member, slot.combat, slot.conflict))
+            .collect()
+    }
+
+    fn fills(expected: &[(&str, Option<&str>)]) -> Vec<(String, Option<String>, bool, bool)> {
+        expected
+            .iter()
+            .map(|(unit, member)| {
+                (
+                    (*unit).to_owned(),
+                    member.map(str::to_owned),
+                    *unit == "gi",
+                    false,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn rarest_units_are_handed_out_first() {
+        let guild = r#"
+            [[Member]]
+            name = "A"
+            Unit = [ { id = "gi", relic = 5 }, { id = "reva", relic = 5 } ]
+
+            [[Member]]
+            name = "B"
+            Unit = [ { id = "gi", relic = 5 } ]
+        "#;
+        assert_eq!(
+            fill(r#"[ "gi", "reva" ]"#, guild),
+            fills(&[("gi", Some("B")), ("reva", Some("A"))]),
+            "only A owns Reva, so B fills the Grand Inquisitor slot"
+        );
+    }
+
+    #[test]
+    fn units_are_used_once_per_member() {
+        let guild = r#"
+            [[Member]]
+            name = "A"
+            Unit = [ { id = "reva", relic = 5 } ]
+        "#;
+        assert_eq!(
+            fill(r#"[ "reva", "reva" ]"#, guild),
+            fills(&[("reva", Some("A")), ("reva", None)]),
+            "A can only fill one of the slots"
+        );
+    }
+
+    #[test]
+    fn low_relics_are_skipped() {
+        let guild = r#"
+            [[Member]]
+            name = "A"
+            Unit = [ { id = "reva", relic = 4 }, { id = "gi" } ]
+        "#;
+        assert_eq!(
+            fill(r#"[ "reva", "gi" ]"#, guild),
+            fills(&[("reva", None), ("gi", None)]),
+            "R4 and Gear 12 units cannot fill R5 slots"
+        );
+    }
+
+    #[test]
+    fn members_needing_a_unit_for_combat_are_avoided() {
+        let guild = r#"
+            [[Member]]
+            name = "A"
+            Unit = [ { id = "gi", relic = 7 } ]
+
+            [[Member]]
+            name = "B"
+            Unit = [ { id = "gi", relic = 5 } ]
+        "#;
+        assert_eq!(
+            fill(r#"[ "gi" ]"#, guild),
+            fills(&[("gi", Some("B"))]),
+            "A needs the Grand Inquisitor for P1DCM1, B cannot play it"
+        );
+
+        let guild = r#"
+            [[Member]]
+            name = "A"
+            Unit = [ { id = "gi", relic = 7 } ]
+        "#;
+        assert_eq!(
+            fill(r#"[ "gi" ]"#, guild),
+            [("gi".to_owned(), Some("A".to_owned()), true, true)],
+            "A is the only option despite the conflict"
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use std::collections::HashSet;
+
+use crate::{Guild, Mission, Phase, Roster};
+
+/// the missions a single player should attempt in a phase
+#[derive(Debug, Clone)]
+pub struct Plan {
+    pub player: String,
+    /// number of the phase, starting at 1
+    pub phase: usize,
+    pub picks: Vec<Pick>,
+    /// mission IDs the player cannot attempt with their roster
+    pub impossible: Vec<String>,
+    /// expected territory points of all picks, see [`Plan::has_points`]
+    pub points: u64,
+}
+
+impl Plan {
+    /// whether all picks have point data, otherwise
+    /// [`Plan::points`] mostly counts the missions
+    pub fn has_points(&self) -> bool {
+        self.picks
+            .iter()
+            .all(|pick| pick.mission.points().is_some())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Pick {
+    /// the chosen team, either the recommendation
+    /// or one of its alternatives
+    pub mission: Mission,
+    /// lowercase IDs of the units filling its slots
+    pub units: Vec<String>,
+    pub points: u64,
+}
+
+/// expected territory points of a mission: the points of all
+/// its waves, see [`Mission::points`]
+///
+/// missions without point data are worth 1, so without any
+/// point data the most missions are picked
+pub fn default_points(mission: &Mission) -> u64 {
+    mission.points().unwrap_or(1)
+}
+
+/// a mission ID together with all its teams the player can field
+struct Candidate<'a> {
+    options: Vec<(&'a Mission, Vec<String>, u64)>,
+    best: u64,
+}
+
+impl Phase {
+    /// picks the missions `roster` should attempt, so that no
+    /// unit is used twice and the total expected territory
+    /// `points` are maximised
+    ///
+    /// missions sharing an ID are treated as alternative teams
+    /// for the same mission and at most one of them is picked
+    pub fn plan<F>(&self, num: usize, roster: &Roster, points: F) -> Plan
+    where
+        F: Fn(&Mission) -> u64,
+    {
+        let mut candidates: Vec<(&str, Candidate<'_>)> = Vec::new();
+        let mut ids: Vec<&str> = Vec::new();
+
+        for mission in self.iter().flat_map(|planet| &planet.missions) {
+            if !ids.contains(&mission.id.as_str()) {
+                ids.push(&mission.id);
+            }
+            if !roster.can_play(mission) {
+                continue;
+            }
+            let Some(units) = roster.team(mission) else {
+                continue;
+            };
+            let value = points(mission);
+
+            match candidates.iter_mut().find(|(id, _)| *id == mission.id) {
+                Some((_, candidate)) => {
+                    candidate.best = candidate.best.max(value);
+                    candidate.options.push((mission, units, value));
+                }
+                None => candidates.push((
+                    &mission.id,
+                    Candidate {
+                        options: vec![(mission, units, value)],
+                        best: value,
+                    },
+                )),
+            }
+        }
+
+        // most valuable missions first to find good solutions early
+        candidates.sort_by(|a, b| b.1.best.cmp(&a.1.best));
+        let candidates = candidates.into_iter().map(|(_, c)| c).collect::<Vec<_>>();
+
+        let mut search = Search {
+            candidates: &candidates,
+            used: HashSet::new(),
+            current: Vec::new(),
+            best: Vec::new(),
+            best_points: 0,
+        };
+        search.run(0, 0);
+
+        let picks = search
+            .best
+            .iter()
+            .map(|(c, o)| {
+                let (mission, units, points) = &candidates[*c].options[*o];
+                Pick {
+                    mission: (*mission).clone(),
+                    units: units.clone(),
+                    points: *points,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Plan {
+            player: roster.name.clone(),
+            phase: num,
+            impossible: ids
+                .into_iter()
+                .filter(|id| !picks.iter().any(|p| p.mission.id == *id))
+                .filter(|id| {
+                    !self
+                        .iter()
+                        .flat_map(|planet| &planet.missions)
+                        .any(|m| m.id == *id && roster.can_play(m))
+                })
+                .map(str::to_owned)
+                .collect(),
+            points: search.best_points,
+            picks,
+        }
+    }
+}
+
+impl Guild {
+    /// a plan for every member of the guild
+    pub fn plans<F>(&self, phase: &Phase, num: usize, points: F) -> Vec<Plan>
+    where
+        F: Fn(&Mission) -> u64,
+    {
+        self.members
+            .iter()
+            .map(|roster| phase.plan(num, roster, &points))
+            .collect()
+    }
+}
+
+/// branch and bound search over all candidates
+struct Search<'a, 'b> {
+    candidates: &'b [Candidate<'a>],
+    used: HashSet<String>,
+    /// picked (candidate, option) indices
+    current: Vec<(usize, usize)>,
+    best: Vec<(usize, usize)>,
+    best_points: u64,
+}
+
+impl Search<'_, '_> {
+    fn run(&mut self, idx: usize, points: u64) {
+        if points > self.best_points {
+            self.best_points = points;
+            self.best = self.current.clone();
+        }
+        if idx >= self.candidates.len() {
+            return;
+        }
+
+        let bound = points + self.candidates[idx..].iter().map(|c| c.best).sum::<u64>();
+        if bound <= self.best_points {
+            return;
+        }
+
+        let candidates = self.candidates;
+        for (o, (_, units, value)) in candidates[idx].options.iter().enumerate() {
+            if units.iter().any(|unit| self.used.contains(unit)) {
+                continue;
+            }
+            self.used.extend(units.iter().cloned());
+            self.current.push((idx, o));
+
+            self.run(idx + 1, points + value);
+
+            self.current.pop();
+            for unit in units {
+                self.used.remove(unit);
+            }
+        }
+
+        // skip this mission entirely
+        self.run(idx + 1, points);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::teams::test_phase;
+
+    fn phase(points: [&str; 3]) -> Phase {
+        let [both, gi, reva] = points;
+        let toml = format!(
+            r#"
+            Mixed = {{ name = "Corellia", relic = 5, mission = [] }}
+            Light = {{ name = "Coruscant", relic = 5, mission = [] }}
+
+            [Dark]
+            name = "Mustafar"
+            relic = 5
+            mission = [
+                {{ id = "P1DCM1", name = "Both", team = [ "gi", "reva", "", "", "" ], note = [], {both} }},
+                {{ id = "P1DCM2", name = "GI", team = [ "gi", "", "", "", "" ], note = [], {gi} }},
+                {{ id = "P1DCM3", name = "Reva", team = [ "reva", "", "", "", "" ], note = [], {reva} }},
+            ]
+            "#
+        );
+        test_phase(&toml)
+    }
+
+    fn picks(plan: &Plan) -> Vec<&str> {
+        plan.picks
+            .iter()
+            .map(|pick| pick.mission.id.as_str())
+            .collect()
+    }
+
+    fn roster() -> Roster {
+        toml::from_str(
+            r#"
+            name = "A"
+            Unit = [ { id = "gi", relic = 5 }, { id = "reva", relic = 5 } ]
+            "#,
+        )
+        .expect("valid roster")
+    }
+
+    #[test]
+    fn points_outweigh_the_number_of_missions() {
+        let points = |n: u64| format!("rewards = [ {{ type = \"points\", waves = [ {n} ] }} ]");
+        let phase = phase(
+            [points(500), points(100), points(100)]
+                .each_ref()
+                .map(String::as_str),
+        );
+        let plan = phase.plan(1, &roster(), default_points);
+        assert_eq!(picks(&plan), ["P1DCM1"], "500 points beat 2 x 100");
+        assert_eq!(plan.points, 500, "points of the pick");
+        assert!(plan.has_points(), "all picks have point data");
+    }
+
+    #[test]
+    fn without_points_the_most_missions_are_picked() {
+        let phase = phase(["auto = \"full\""; 3]);
+        let mut plan = phase.plan(1, &roster(), default_points);
+        plan.picks.sort_by(|a, b| a.mission.id.cmp(&b.mission.id));
+        assert_eq!(picks(&plan), ["P1DCM2", "P1DCM3"], "two missions beat one");
+        assert!(!plan.has_points(), "no point data");
+    }
+}
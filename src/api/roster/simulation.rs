@@ -0,0 +1,414 @@
+use crate::{
+    Guild, Mission, Phase, Plan, Planet, PlanetDeployment, Teams, allocate, deployable,
+    mission_points, preload,
+};
+
+/// choices of the officers that change the outcome of the battle
+#[derive(Debug, Clone)]
+pub struct Strategy {
+    /// planets nobody deploys to or attempts missions on,
+    /// bonus planets listed here are never unlocked
+    pub skip: Vec<String>,
+    /// planets that get GP before all others
+    pub priority: Vec<String>,
+    /// chance that an attempted combat mission succeeds
+    pub success_rate: f64,
+}
+
+impl Default for Strategy {
+    fn default() -> Self {
+        Self {
+            skip: Vec::new(),
+            priority: Vec::new(),
+            success_rate: 1.0,
+        }
+    }
+}
+
+/// expected outcome of the whole battle
+#[derive(Debug, Clone)]
+pub struct Simulation {
+    pub phases: Vec<PhaseSimulation>,
+    pub stars: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct PhaseSimulation {
+    /// number of the phase, starting at 1
+    pub phase: usize,
+    /// the open planets, `points` are those carried over
+    /// from earlier phases plus the expected points of
+    /// combat missions
+    pub planets: Vec<PlanetDeployment>,
+    /// bonus planets unlocked during this phase
+    pub unlocked: Vec<String>,
+    pub stars: u32,
+}
+
+impl Teams {
+    /// plays out all phases of the battle with the rosters of `guild`
+    ///
+    /// the model is deliberately simple:
+    /// - every phase each member attempts the missions of their
+    ///   [`Plan`], each succeeding with `strategy.success_rate`
+    /// - all GP not used by missions or operations is deployed,
+    ///   first to the planets in `strategy.priority`, then always
+    ///   to the cheapest next star, the rest is preloaded into the
+    ///   planet closest to its next star
+    /// - a planet with at least one star opens the next planet of
+    ///   its alignment, a planet without stars stays open and keeps
+    ///   its points
+    /// - a bonus planet opens in its phase if its special mission
+    ///   succeeded in an earlier phase with a chance of at least
+    ///   50%, only one bonus planet can be open at a time
+    /// - planets without star thresholds never get stars
+    pub fn simulate<F>(&self, guild: &Guild, strategy: &Strategy, points: F) -> Simulation
+    where
+        F: Fn(&Mission) -> u64,
+    {
+        let alignments: [fn(&Phase) -> &Planet; 3] = [|p| &p.dark, |p| &p.mixed, |p| &p.light];
+        // phase index and carried points of the open planet per alignment
+        let mut open = [(0, 0); 3];
+        let mut bonus: Option<(&Planet, u64)> = None;
+        let mut unlocked: Vec<String> = Vec::new();
+        let mut phases = Vec::new();
+
+        for (idx, current) in self.phases.iter().enumerate() {
+            let num = idx + 1;
+            if bonus.is_none() {
+                bonus = current
+                    .bonus
+                    .as_ref()
+                    .filter(|b| unlocked.contains(&b.name))
+                    .map(|b| (b, 0));
+            }
+
+            let open_planet = |planet: &Planet| {
+                let mut planet = planet.clone();
+                if strategy.skip.contains(&planet.name) {
+                    planet.missions.clear();
+                }
+                planet
+                    .missions
+                    .retain(|m| !strategy.skip.iter().any(|s| unlocks(m, s)));
+                planet
+            };
+            let phase = Phase {
+                dark: open_planet(alignments[0](&self.phases[open[0].0])),
+                mixed: open_planet(alignments[1](&self.phases[open[1].0])),
+                light: open_planet(alignments[2](&self.phases[open[2].0])),
+                bonus: bonus.map(|(b, _)| open_planet(b)),
+                locked: false,
+            };
+
+            let plans = guild.plans(&phase, num, &points);
+            let operations = &self.operations[idx];
+            let operations = operations
+                .iter()
+                .any(|planet| !planet.operations.is_empty())
+                .then(|| operations.fill(&phase, num, guild));
+            let gp = deployable(guild, &plans, operations.as_ref());
+
+            let carried = alignments
+                .iter()
+                .zip(&open)
+                .map(|(alignment, (pos, points))| {
+                    (alignment(&self.phases[*pos]).name.as_str(), *points)
+                })
+                .chain(bonus.map(|(planet, points)| (planet.name.as_str(), points)))
+                .collect::<Vec<_>>();
+            let mut planets = deployments(&phase, &carried, &plans, strategy);
+
+            let (mut first, mut rest): (Vec<_>, Vec<_>) = planets
+                .drain(..)
+                .partition(|p| strategy.priority.contains(&p.name));
+            let leftover = allocate(&mut first, gp);
+            first.append(&mut rest);
+            first.sort_by_key(|p| phase.iter().position(|planet| planet.name == p.name));
+            planets = first;
+            let leftover = allocate(&mut planets, leftover);
+            preload(&mut planets, leftover);
+
+            let result = |planet: &Planet| planets.iter().find(|p| p.name == planet.name);
+            for (alignment, (pos, carried)) in alignments.iter().zip(open.iter_mut()) {
+                match result(alignment(&self.phases[*pos])) {
+                    Some(p) if p.stars > 0 => {
+                        *pos += 1;
+                        *carried = 0;
+                    }
+                    Some(p) => *carried = p.points + p.deploy,
+                    None => {}
+                }
+            }
+            bonus = bonus.and_then(|(planet, carried)| match result(planet) {
+                Some(p) if p.stars > 0 => None,
+                Some(p) => Some((planet, p.points + p.deploy)),
+                None => Some((planet, carried)),
+            });
+
+            let newly = self.phases[num..]
+                .iter()
+                .filter_map(|p| p.bonus.as_ref())
+                .filter(|b| !unlocked.contains(&b.name) && !strategy.skip.contains(&b.name))
+                .filter(|b| unlock_chance(&b.name, &plans, strategy.success_rate) >= 0.5)
+                .map(|b| b.name.clone())
+                .collect::<Vec<_>>();
+            unlocked.extend(newly.iter().cloned());
+
+            let stars = planets.iter().map(|p| u32::from(p.stars)).sum();
+            phases.push(PhaseSimulation {
+                phase: num,
+                planets,
+                unlocked: newly,
+                stars,
+            });
+        }
+
+        Simulation {
+            stars: phases.iter().map(|p| p.stars).sum(),
+            phases,
+        }
+    }
+}
+
+/// the planets of `phase` which are not skipped together with the
+/// points `carried` over by planet name and those of planned missions
+///
+/// matched by name as the bonus planet is not always the last one
+fn deployments(
+    phase: &Phase,
+    carried: &[(&str, u64)],
+    plans: &[Plan],
+    strategy: &Strategy,
+) -> Vec<PlanetDeployment> {
+    phase
+        .iter()
+        .filter(|planet| !strategy.skip.contains(&planet.name))
+        .map(|planet| {
+            let carried = carried
+                .iter()
+                .find(|(name, _)| *name == planet.name)
+                .map_or(0, |(_, points)| *points);
+            PlanetDeployment {
+                name: planet.name.clone(),
+                points: carried + mission_points(planet, plans, strategy.success_rate),
+                deploy: 0,
+                stars: 0,
+                thresholds: planet.thresholds,
+            }
+        })
+        .collect()
+}
+
+/// whether `mission` unlocks the bonus planet `planet`
+fn unlocks(mission: &Mission, planet: &str) -> bool {
+    mission.unlocks.as_deref() == Some(planet)
+}
+
+/// chance that at least one member succeeds at the
+/// special mission unlocking `planet`
+fn unlock_chance(planet: &str, plans: &[Plan], success_rate: f64) -> f64 {
+    let attempts = plans
+        .iter()
+        .flat_map(|plan| &plan.picks)
+        .filter(|pick| unlocks(&pick.mission, planet))
+        .count();
+    let attempts = i32::try_from(attempts).unwrap_or(i32::MAX);
+    1.0 - (1.0 - success_rate).powi(attempts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::teams::test_phase;
+
+    /// a phase with one special mission on the light side
+    /// planet, unlocking the bonus planet of phase 2
+    const PHASE1: &str = r#"
+Dark = { name = "Mustafar", thresholds = [ 500, 2000, 3000 ], mission = [] }
+Mixed = { name = "Corellia", mission = [] }
+
+[Light]
+name = "Coruscant"
+relic = 5
+thresholds = [ 1000, 2000, 3000 ]
+
+[[Light.mission]]
+id = "P1LSM"
+name = "Special Mission"
+team = [ "reva", "", "", "", "" ]
+note = []
+unlocks = "Zeffo"
+"#;
+
+    const PHASE2: &str = r#"
+Dark = { name = "Geonosis", mission = [] }
+Mixed = { name = "Felucia", mission = [] }
+Light = { name = "Bracca", mission = [] }
+Bonus = { name = "Zeffo", unlock = "P1LSM", mission = [] }
+"#;
+
+    const OPERATIONS: &str = r#"
+Dark = { name = "", relic = 5 }
+Mixed = { name = "", relic = 5 }
+Light = { name = "", relic = 5 }
+"#;
+
+    fn teams() -> Teams {
+        Teams {
+            phases: vec![test_phase(PHASE1), test_phase(PHASE2)],
+            operations: vec![
+                toml::from_str(OPERATIONS).expect("valid operations"),
+                toml::from_str(OPERATIONS).expect("valid operations"),
+            ],
+        }
+    }
+
+    /// `members` members with `gp` character GP each, all owning Reva
+    fn guild(members: usize, gp: u64) -> Guild {
+        let members = (0..members)
+            .map(|idx| {
+                format!(
+                    "[[Member]]\nname = \"{idx}\"\ncharacter_gp = {gp}\nship_gp = 0\n\
+                     Unit = [ {{ id = \"reva\", relic = 5 }} ]\n"
+                )
+            })
+            .collect::<String>();
+        Guild::from_toml(&members).expect("valid guild")
+    }
+
+    /// names and stars of the planets of each phase
+    fn stars(simulation: &Simulation) -> Vec<Vec<(&str, u8)>> {
+        simulation
+            .phases
+            .iter()
+            .map(|phase| {
+                phase
+                    .planets
+                    .iter()
+                    .map(|planet| (planet.name.as_str(), planet.stars))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn points_are_carried_to_the_same_planet() {
+        let teams = teams();
+        let guild = guild(1, 0);
+
+        let simulation = teams.simulate(&guild, &Strategy::default(), |_| 10);
+        let points = simulation
+            .phases
+            .iter()
+            .map(|phase| {
+                phase
+                    .planets
+                    .iter()
+                    .map(|planet| (planet.name.as_str(), planet.points))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            points,
+            [
+                vec![("Mustafar", 0), ("Corellia", 0), ("Coruscant", 10)],
+                // Zeffo is placed in front of Coruscant
+                vec![
+                    ("Mustafar", 0),
+                    ("Corellia", 0),
+                    ("Zeffo", 0),
+                    ("Coruscant", 20)
+                ],
+            ],
+            "Coruscant keeps its points without a star"
+        );
+        assert_eq!(
+            simulation.phases[0].unlocked,
+            ["Zeffo"],
+            "P1LSM unlocks Zeffo"
+        );
+    }
+
+    #[test]
+    fn a_star_opens_the_next_planet() {
+        let simulation = teams().simulate(&guild(1, 1000), &Strategy::default(), |_| 10);
+        assert_eq!(
+            stars(&simulation),
+            [
+                vec![("Mustafar", 1), ("Corellia", 0), ("Coruscant", 0)],
+                vec![
+                    ("Geonosis", 0),
+                    ("Corellia", 0),
+                    ("Zeffo", 0),
+                    ("Coruscant", 1)
+                ],
+            ],
+            "the cheapest star is bought on Mustafar and Geonosis follows it, \
+             Coruscant has the only thresholds in the second phase"
+        );
+    }
+
+    #[test]
+    fn priority_planets_get_gp_first() {
+        let strategy = Strategy {
+            priority: vec!["Coruscant".to_owned()],
+            ..Strategy::default()
+        };
+        let simulation = teams().simulate(&guild(1, 1000), &strategy, |_| 10);
+        assert_eq!(
+            stars(&simulation)[0],
+            [("Mustafar", 0), ("Corellia", 0), ("Coruscant", 1)],
+            "Coruscant is bought although Mustafar is cheaper"
+        );
+        assert_eq!(
+            simulation.phases[1].planets.last().map(|p| p.name.as_str()),
+            Some("Bracca"),
+            "Bracca follows Coruscant"
+        );
+    }
+
+    #[test]
+    fn skipped_bonus_planets_stay_locked() {
+        let strategy = Strategy {
+            skip: vec!["Zeffo".to_owned()],
+            ..Strategy::default()
+        };
+        let simulation = teams().simulate(&guild(1, 0), &strategy, |_| 10);
+        assert!(
+            simulation.phases.iter().all(|p| p.unlocked.is_empty()),
+            "Zeffo is never unlocked"
+        );
+        assert!(
+            simulation.phases[1]
+                .planets
+                .iter()
+                .all(|planet| planet.name != "Zeffo"),
+            "Zeffo is not deployed to"
+        );
+        assert_eq!(
+            simulation.phases[0].planets[2].points, 0,
+            "its special mission is not attempted"
+        );
+    }
+
+    #[test]
+    fn unlikely_special_missions_do_not_unlock() {
+        let strategy = Strategy {
+            success_rate: 0.4,
+            ..Strategy::default()
+        };
+        let one = teams().simulate(&guild(1, 0), &strategy, |_| 10);
+        assert!(
+            one.phases[0].unlocked.is_empty(),
+            "a single attempt succeeds with 40%"
+        );
+
+        let two = teams().simulate(&guild(2, 0), &strategy, |_| 10);
+        assert_eq!(
+            two.phases[0].unlocked,
+            ["Zeffo"],
+            "one of two attempts succeeds with 64%"
+        );
+    }
+}
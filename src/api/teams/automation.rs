@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+/// how much manual input a mission needs
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum Auto {
+    /// the whole mission runs on auto
+    Full,
+    /// a few manual moves, e.g. at the start
+    /// of a wave, then auto
+    Partial,
+    /// needs to be played manually
+    Manual,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl Auto {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Full => "Full Auto",
+            Self::Partial => "Partial Auto",
+            Self::Manual => "Manual",
+        }
+    }
+}
+
+impl Difficulty {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Easy => "Easy",
+            Self::Medium => "Medium",
+            Self::Hard => "Hard",
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! checks of the data files, `build.rs` includes this file and
+//! `mission_id.rs` to run the same checks, so both only use `std`
+
+use super::mission_id::{Alignment, MissionId, MissionKind};
+
+/// the problem with the ID of mission `id` named `name` on the `alignment`
+/// planet `planet` of phase `num`, e.g. an ID of another phase
+pub fn check_id(
+    id: &str,
+    name: &str,
+    planet: &str,
+    num: usize,
+    alignment: Alignment,
+) -> Option<String> {
+    let parsed = match id.parse::<MissionId>() {
+        Ok(parsed) => parsed,
+        Err(err) => return Some(err),
+    };
+    if usize::from(parsed.phase) != num {
+        Some(format!(
+            "{id} on {planet} belongs to phase {}, not {num}",
+            parsed.phase
+        ))
+    } else if parsed.alignment != alignment {
+        Some(format!(
+            "{id} on {planet} is {:?}, but the planet is {alignment:?}",
+            parsed.alignment
+        ))
+    } else if (parsed.kind == MissionKind::Fleet) != is_fleet(name) {
+        Some(format!("{id} on {planet} is named {name}"))
+    } else {
+        None
+    }
+}
+
+/// the problem with mission `id` named `name` on `planet` requiring
+/// `relic`, the planet requirement is the minimum of all its missions
+/// and fleets have none
+pub fn check_relic(
+    id: &str,
+    name: &str,
+    planet: &str,
+    relic: Option<u8>,
+    planet_relic: Option<u8>,
+) -> Option<String> {
+    match (relic, planet_relic) {
+        (Some(_), _) if is_fleet(name) => Some(format!(
+            "{id} on {planet} is a fleet with a relic requirement"
+        )),
+        // most likely copy-pasted from an earlier phase
+        (Some(own), Some(min)) if own < min => Some(format!(
+            "{id} on {planet} requires R{own}, but the planet requires R{min}"
+        )),
+        _ => None,
+    }
+}
+
+/// fleet missions are named `Fleet`
+pub fn is_fleet(name: &str) -> bool {
+    name == "Fleet"
+}
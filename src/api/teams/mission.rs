@@ -1,8 +1,19 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use crate::api::teams::{omicron::Omicrons, video::Video};
+use crate::api::teams::{
+    automation::{Auto, Difficulty},
+    check::is_fleet,
+    mission_id::MissionId,
+    omicron::Omicrons,
+    requirement::Requirement,
+    reward::Reward,
+    slot::Slot,
+    video::Video,
+    wave::Wave,
+};
 
-#[derive(Debug, Deserialize, Clone, Hash)]
+#[derive(Debug, Serialize, Deserialize, Clone, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Mission {
     /// the ID of a mission
     ///
@@ -12,23 +23,78 @@ pub struct Mission {
     pub id: String,
     /// Name of the Mission
     pub name: String,
-    /// list of unit IDs which form the
-    /// team used for this mission
-    pub team: Vec<String>,
+    /// slots which form the team used for
+    /// this mission, usually unit IDs
+    pub team: Vec<Slot>,
     /// additional note giving information
     /// about this mission
     ///
     /// each element is one paragraph
     pub note: Vec<String>,
+    /// how much manual input this mission needs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto: Option<Auto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub difficulty: Option<Difficulty>,
     /// the relic requirement of this
-    /// mission
+    /// mission if it differs from its planet
     ///
     /// fleet mission only require 7 stars
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub relic: Option<u8>,
+    /// relic requirement of the planet
+    #[serde(skip)]
+    pub(crate) planet_relic: Option<u8>,
     /// omicrons required for this mission
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub omicrons: Option<Vec<Omicrons>>,
+    /// extra requirements of single team
+    /// slots, e.g. a zeta or a minimum speed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requirements: Option<Vec<Requirement>>,
     /// videos showcasing this team
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub videos: Option<Vec<Video>>,
+    /// effects active during the whole mission
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modifiers: Option<Vec<String>>,
+    /// enemies in the order they appear
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub waves: Option<Vec<Wave>>,
+    /// rewards for completing this mission
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rewards: Option<Vec<Reward>>,
+    /// name of the bonus planet this
+    /// mission unlocks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unlocks: Option<String>,
     /// modding recommendation
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub modding: Option<String>,
 }
+
+impl Mission {
+    pub fn is_fleet(&self) -> bool {
+        is_fleet(&self.name)
+    }
+
+    pub fn mission_id(&self) -> Result<MissionId, String> {
+        self.id.parse()
+    }
+
+    pub fn is_full_auto(&self) -> bool {
+        self.auto == Some(Auto::Full)
+    }
+
+    /// the relic requirement of this mission, inherited
+    /// from its planet unless overridden
+    ///
+    /// `None` for fleets
+    pub fn relic(&self) -> Option<u8> {
+        if self.is_fleet() {
+            None
+        } else {
+            self.relic.or(self.planet_relic)
+        }
+    }
+}
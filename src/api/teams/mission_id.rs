@@ -0,0 +1,176 @@
+use std::{fmt::Display, str::FromStr};
+
+/// a structured mission ID like `P1DCM1`
+///
+/// `P`, the number of the phase, the alignment of the
+/// planet (`D`ark, `M`ixed, `L`ight or `B`onus) and
+/// the kind of the mission
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MissionId {
+    pub phase: u8,
+    pub alignment: Alignment,
+    pub kind: MissionKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Alignment {
+    Dark,
+    Mixed,
+    Light,
+    Bonus,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MissionKind {
+    /// `CM`, numbered if a planet has several
+    Combat(Option<u8>),
+    /// `SM`
+    Special,
+    /// `F`
+    Fleet,
+    /// a combat mission named after its team,
+    /// e.g. `LV` for Lord Vader
+    Named(String),
+}
+
+impl Alignment {
+    pub const ALL: [Self; 4] = [Self::Dark, Self::Mixed, Self::Light, Self::Bonus];
+
+    pub fn letter(self) -> char {
+        match self {
+            Self::Dark => 'D',
+            Self::Mixed => 'M',
+            Self::Light => 'L',
+            Self::Bonus => 'B',
+        }
+    }
+
+    fn from_letter(c: char) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|a| a.letter().eq_ignore_ascii_case(&c))
+    }
+}
+
+impl MissionKind {
+    /// whether this is a combat mission, numbered or named
+    pub fn is_combat(&self) -> bool {
+        matches!(self, Self::Combat(_) | Self::Named(_))
+    }
+}
+
+impl FromStr for MissionId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rest = s
+            .strip_prefix(['P', 'p'])
+            .ok_or_else(|| format!("mission ID `{s}` does not start with P"))?;
+        let digits = rest.chars().take_while(char::is_ascii_digit).count();
+        let (phase, rest) = rest.split_at(digits);
+        let phase = phase
+            .parse()
+            .map_err(|err| format!("mission ID `{s}` has no phase: {err}"))?;
+        let mut chars = rest.chars();
+        let alignment = chars
+            .next()
+            .and_then(Alignment::from_letter)
+            .ok_or_else(|| format!("mission ID `{s}` has no alignment (D, M, L or B)"))?;
+        let kind = chars.as_str().parse()?;
+        Ok(Self {
+            phase,
+            alignment,
+            kind,
+        })
+    }
+}
+
+impl FromStr for MissionKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let upper = s.to_ascii_uppercase();
+        Ok(match upper.as_str() {
+            "" => return Err("mission ID has no mission".to_owned()),
+            "CM" => Self::Combat(None),
+            "SM" => Self::Special,
+            "F" => Self::Fleet,
+            cm if cm.starts_with("CM") && cm[2..].chars().all(|c| c.is_ascii_digit()) => {
+                Self::Combat(cm[2..].parse().ok())
+            }
+            _ => Self::Named(s.to_owned()),
+        })
+    }
+}
+
+impl Display for MissionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "P{}{}{}", self.phase, self.alignment.letter(), self.kind)
+    }
+}
+
+impl Display for MissionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Combat(None) => f.write_str("CM"),
+            Self::Combat(Some(n)) => write!(f, "CM{n}"),
+            Self::Special => f.write_str("SM"),
+            Self::Fleet => f.write_str("F"),
+            Self::Named(name) => f.write_str(name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id(phase: u8, alignment: Alignment, kind: MissionKind) -> MissionId {
+        MissionId {
+            phase,
+            alignment,
+            kind,
+        }
+    }
+
+    #[test]
+    fn mission_ids_are_parsed() {
+        let cases = [
+            (
+                "P1DCM1",
+                id(1, Alignment::Dark, MissionKind::Combat(Some(1))),
+            ),
+            ("P3MCM", id(3, Alignment::Mixed, MissionKind::Combat(None))),
+            ("p2lsm", id(2, Alignment::Light, MissionKind::Special)),
+            ("P3MF", id(3, Alignment::Mixed, MissionKind::Fleet)),
+            ("P3BF", id(3, Alignment::Bonus, MissionKind::Fleet)),
+            (
+                "P1DLV",
+                id(1, Alignment::Dark, MissionKind::Named("LV".to_owned())),
+            ),
+            (
+                "P3MFS",
+                id(3, Alignment::Mixed, MissionKind::Named("FS".to_owned())),
+            ),
+            (
+                "P10LCM2",
+                id(10, Alignment::Light, MissionKind::Combat(Some(2))),
+            ),
+        ];
+        for (s, expected) in cases {
+            let parsed = s.parse::<MissionId>().expect(s);
+            assert_eq!(parsed, expected, "{s}");
+            assert!(
+                parsed.to_string().eq_ignore_ascii_case(s),
+                "{s} is written as {parsed}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_mission_ids_are_rejected() {
+        for s in ["", "1DCM1", "PDCM1", "P1", "P1XCM1", "P1D"] {
+            assert!(s.parse::<MissionId>().is_err(), "{s:?} is not a mission ID");
+        }
+    }
+}
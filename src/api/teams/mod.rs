@@ -1,69 +1,233 @@
+mod automation;
+mod check;
 mod mission;
+mod mission_id;
 mod omicron;
+mod operations;
 mod phase;
 mod planet;
+mod query;
+mod requirement;
+mod reward;
+mod slot;
 mod video;
+mod wave;
 
+pub use automation::*;
 pub use mission::*;
+pub use mission_id::*;
 pub use omicron::*;
+pub use operations::*;
 pub use phase::*;
 pub use planet::*;
+pub use query::*;
+pub use requirement::*;
+pub use reward::*;
+pub use slot::*;
 pub use video::*;
+pub use wave::*;
+
+use crate::{LoadError, Units, api::load::parse};
 
 macro_rules! phases {
-    ( $($num:literal),+ ) => {
+    ( $parse:expr, $file:literal; $($num:literal),+ ) => {
         vec![$(
-            toml::from_slice(include_bytes!(concat!("../../../assets/data/Phase", $num, ".toml")))
-                .expect(&format!("failed to load phase {}", $num)),
+            $parse(
+                concat!($file, $num, ".toml"),
+                include_bytes!(concat!("../../../assets/data/", $file, $num, ".toml")),
+            )?,
         )+]
     };
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct Teams {
     pub phases: Vec<Phase>,
+    /// operations of each phase, same order as `phases`
+    pub operations: Vec<Operations>,
 }
 
 impl Teams {
-    pub fn load() -> Self {
-        Self {
-            phases: phases!(1, 2, 3, 4, 5, 6),
-        }
+    /// loads all phases, filling their tag slots with `units`
+    pub fn load(units: &Units) -> Result<Self, LoadError> {
+        let parse_phase = |file, bytes| Phase::parse(file, bytes, units);
+        Ok(Self {
+            phases: phases!(parse_phase, "Phase"; 1, 2, 3, 4, 5, 6),
+            operations: phases!(parse, "Operations"; 1, 2, 3, 4, 5, 6),
+        })
     }
 
-    pub fn search(&self, s: &str) -> Vec<Mission> {
-        let mut found = Vec::new();
-
-        for phase in &self.phases {
-            for mission in &phase.dark.missions {
-                if mission
-                    .id
-                    .to_ascii_lowercase()
-                    .starts_with(&s.to_ascii_lowercase())
-                {
-                    found.push(mission.clone());
-                }
-            }
-            for mission in &phase.mixed.missions {
-                if mission
-                    .id
-                    .to_ascii_lowercase()
-                    .starts_with(&s.to_ascii_lowercase())
-                {
-                    found.push(mission.clone());
-                }
-            }
-            for mission in &phase.light.missions {
-                if mission
-                    .id
-                    .to_ascii_lowercase()
-                    .starts_with(&s.to_ascii_lowercase())
-                {
-                    found.push(mission.clone());
-                }
+    /// all missions together with the number
+    /// of their phase and their planet
+    pub fn missions(&self) -> impl Iterator<Item = (usize, &Planet, &Mission)> {
+        self.phases.iter().enumerate().flat_map(|(idx, phase)| {
+            phase.iter().flat_map(move |planet| {
+                planet
+                    .missions
+                    .iter()
+                    .map(move |mission| (idx + 1, planet, mission))
+            })
+        })
+    }
+
+    /// all missions using the unit `id`
+    pub fn missions_with(&self, id: &str) -> impl Iterator<Item = (usize, &Planet, &Mission)> {
+        self.missions()
+            .filter(move |(_, _, mission)| mission.team.iter().any(|slot| slot.accepts(id)))
+    }
+
+    /// missions matching `s`, see [`MissionQuery`]
+    pub fn search(&self, s: &str) -> impl Iterator<Item = (usize, &Planet, &Mission)> {
+        let query = MissionQuery::new(s);
+        let found = self
+            .missions()
+            .any(|(num, planet, mission)| query.matches(num, planet, mission));
+        self.missions().filter(move |(num, planet, mission)| {
+            if found {
+                query.matches(*num, planet, mission)
+            } else {
+                query.matches_name(*num, planet, mission)
             }
-        }
+        })
+    }
+
+    /// problems with the mission IDs of all phases
+    pub fn check_ids(&self) -> Vec<String> {
+        self.phases
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, phase)| phase.check_ids(idx + 1))
+            .collect()
+    }
+
+    /// missions requiring a lower relic than their planet
+    pub fn relic_mismatches(&self) -> Vec<String> {
+        self.phases
+            .iter()
+            .flat_map(Phase::iter)
+            .flat_map(Planet::relic_mismatches)
+            .collect()
+    }
+}
+
+/// parses the phase fixture `toml`, tag slots stay empty
+#[cfg(test)]
+pub(crate) fn test_phase(toml: &str) -> Phase {
+    Phase::parse("test.toml", toml.as_bytes(), &Units::default()).expect("valid phase")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHASE: &str = r#"
+[Dark]
+name = "Mustafar"
+relic = 5
+
+[[Dark.mission]]
+id = "P1DCM1"
+name = "Combat Mission"
+team = [ "reva", "gi", "7sis", "8bro", "5bro" ]
+note = []
+
+[[Dark.mission]]
+id = "P1DSM"
+name = "Special Mission"
+team = [ "reva", "", "", "", "" ]
+note = []
+relic = 7
+
+[Mixed]
+name = "Corellia"
+relic = 5
+
+[[Mixed.mission]]
+id = "P1MF"
+name = "Fleet"
+team = [ "Executor", "", "", "", "", "", "", "" ]
+note = []
+
+[Light]
+name = "Coruscant"
+mission = []
+"#;
 
-        found
+    #[test]
+    fn missions_inherit_the_planet_relic() {
+        let phase = test_phase(PHASE);
+        let relics = phase
+            .iter()
+            .flat_map(|planet| &planet.missions)
+            .map(|mission| (mission.id.as_str(), mission.relic()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            relics,
+            [("P1DCM1", Some(5)), ("P1DSM", Some(7)), ("P1MF", None)],
+            "planet relic unless overridden, none for fleets"
+        );
+    }
+
+    #[test]
+    fn shipped_data_is_consistent() {
+        let teams = Teams::load(&Units::load().expect("units load")).expect("data files load");
+        assert!(teams.check_ids().is_empty(), "{:?}", teams.check_ids());
+        assert!(
+            teams.relic_mismatches().is_empty(),
+            "{:?}",
+            teams.relic_mismatches()
+        );
+    }
+
+    #[test]
+    fn alignment_initials_are_not_planet_names() {
+        let teams = Teams::load(&Units::load().expect("units load")).expect("data files load");
+        let ids = |s: &str| {
+            teams
+                .search(s)
+                .map(|(_, _, mission)| mission.id.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        let mixed = ids("P1MCM");
+        assert!(
+            !mixed.is_empty() && mixed.iter().all(|id| *id == "P1MCM"),
+            "not the combat missions of Mustafar: {mixed:?}"
+        );
+        let mixed = ids("P4MCM");
+        assert!(
+            !mixed.is_empty() && mixed.iter().all(|id| *id == "P4MCM"),
+            "not the combat missions of Mandalore: {mixed:?}"
+        );
+        let bracca = ids("P2BCM");
+        assert!(
+            !bracca.is_empty() && bracca.iter().all(|id| id.starts_with("P2LCM")),
+            "phase 2 has no bonus planet, so B is Bracca: {bracca:?}"
+        );
+    }
+
+    #[test]
+    fn phases_round_trip() {
+        let units = Units::load().expect("units load");
+        let files = [
+            include_str!("../../../assets/data/Phase1.toml"),
+            include_str!("../../../assets/data/Phase2.toml"),
+            include_str!("../../../assets/data/Phase3.toml"),
+            include_str!("../../../assets/data/Phase4.toml"),
+            include_str!("../../../assets/data/Phase5.toml"),
+            include_str!("../../../assets/data/Phase6.toml"),
+            PHASE,
+        ];
+        for (idx, file) in files.into_iter().enumerate() {
+            let name = format!("Phase{}.toml", idx + 1);
+            let phase = Phase::parse(&name, file.as_bytes(), &units).expect("valid phase");
+            let written = toml::to_string(&phase).expect("serializable");
+            let read = Phase::parse(&name, written.as_bytes(), &units).expect("own output parses");
+            assert_eq!(
+                toml::Value::try_from(&read).expect("serializable"),
+                toml::Value::try_from(&phase).expect("serializable"),
+                "{name} is unchanged by a round trip"
+            );
+        }
     }
 }
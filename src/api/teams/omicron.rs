@@ -2,7 +2,8 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Clone, Hash)]
+#[derive(Debug, Serialize, Deserialize, Clone, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Omicrons {
     /// Unit ID which these Omicrons is applied to
     pub unit: String,
@@ -10,7 +11,7 @@ pub struct Omicrons {
     pub omis: Vec<Omicron>,
 }
 
-#[derive(Debug, Clone, Hash, Serialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum Omicron {
     Basic,
     Special(u8),
@@ -40,6 +41,31 @@ impl Display for Omicron {
     }
 }
 
+/// written as its ID, e.g. `"s1"` or `"u2"`
+impl Serialize for Omicron {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_id())
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for Omicron {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Omicron".into()
+    }
+
+    fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "b (basic), l (lead), s<num> (special) or u<num> (unique)",
+            "type": "string",
+            "pattern": "^([bBlL]|[sSuU][0-9]+)$",
+        })
+    }
+}
+
 impl<'de> Deserialize<'de> for Omicron {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -66,7 +92,48 @@ impl<'de> Deserialize<'de> for Omicron {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Ability {
     pub id: String,
     pub name: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn omicrons_round_trip() {
+        let omicrons: Omicrons = toml::from_str(
+            r#"unit = "GI"
+omis = [ "b", "L", "s1", "U2" ]"#,
+        )
+        .expect("valid omicrons");
+        assert_eq!(
+            omicrons.omis,
+            [
+                Omicron::Basic,
+                Omicron::Lead,
+                Omicron::Special(1),
+                Omicron::Unique(2)
+            ],
+            "IDs are case insensitive"
+        );
+        let written = toml::to_string(&omicrons).expect("serializable");
+        assert!(
+            written.contains(r#"omis = ["b", "l", "s1", "u2"]"#),
+            "written as lowercase IDs: {written}"
+        );
+        let read: Omicrons = toml::from_str(&written).expect("own output parses");
+        assert_eq!(read.omis, omicrons.omis, "unchanged by a round trip");
+    }
+
+    #[test]
+    fn invalid_omicrons_are_rejected() {
+        for id in ["", "x1", "s", "ux", "s256"] {
+            let omicrons =
+                toml::from_str::<Omicrons>(&format!("unit = \"GI\"\nomis = [ \"{id}\" ]"));
+            assert!(omicrons.is_err(), "{id:?} is not an omicron");
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+/// the operations (platoons) of a phase
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Operations {
+    #[serde(rename = "Dark")]
+    pub dark: PlanetOperations,
+    #[serde(rename = "Mixed")]
+    pub mixed: PlanetOperations,
+    #[serde(rename = "Light")]
+    pub light: PlanetOperations,
+    #[serde(rename = "Bonus", skip_serializing_if = "Option::is_none")]
+    pub bonus: Option<PlanetOperations>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PlanetOperations {
+    /// name of the planet, matching the one in `PhaseX.toml`
+    pub name: String,
+    /// relic tier all character slots of this planet require,
+    /// ships only require 7 stars, the same as in `PhaseX.toml`
+    pub relic: u8,
+    /// empty as long as the slots of this planet are unknown,
+    /// the planner ignores phases without any operations
+    #[serde(rename = "operation", default)]
+    pub operations: Vec<Operation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Operation {
+    /// number of the operation on its planet
+    pub id: u8,
+    /// unit ID of each slot, a unit may
+    /// fill multiple slots
+    pub slots: Vec<String>,
+}
+
+impl Operations {
+    pub fn iter(&self) -> impl Iterator<Item = &PlanetOperations> {
+        [&self.dark, &self.mixed, &self.light]
+            .into_iter()
+            .chain(self.bonus.as_ref())
+    }
+}
@@ -1,8 +1,12 @@
-use crate::Planet;
+use crate::{
+    Alignment, LoadError, MissionId, Planet, Units,
+    api::{load, teams::check::check_id},
+};
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Phase {
     #[serde(rename = "Dark")]
     pub dark: Planet,
@@ -10,17 +14,104 @@ pub struct Phase {
     pub mixed: Planet,
     #[serde(rename = "Light")]
     pub light: Planet,
-    #[serde(rename = "Bonus")]
+    #[serde(rename = "Bonus", skip_serializing_if = "Option::is_none")]
     pub bonus: Option<Planet>,
+    /// hides the bonus planet, e.g. because
+    /// its special mission was not completed
+    #[serde(skip)]
+    pub locked: bool,
 }
 
 impl Phase {
+    /// parses the phase file `file`, lets all missions inherit the
+    /// relic requirement of their planet and fills tag slots with
+    /// the matching `units`
+    pub fn parse(file: &str, bytes: &[u8], units: &Units) -> Result<Self, LoadError> {
+        let mut phase: Self = load::parse(file, bytes)?;
+        phase.inherit_relic();
+        phase.resolve_tags(units);
+        Ok(phase)
+    }
+
+    /// all planets together with their alignment
+    pub fn planets(&self) -> impl Iterator<Item = (Alignment, &Planet)> {
+        [
+            (Alignment::Dark, Some(&self.dark)),
+            (Alignment::Mixed, Some(&self.mixed)),
+            (Alignment::Light, Some(&self.light)),
+            (Alignment::Bonus, self.bonus.as_ref()),
+        ]
+        .into_iter()
+        .filter_map(|(alignment, planet)| Some((alignment, planet?)))
+    }
+
+    /// problems with the mission IDs of this phase, e.g. an ID
+    /// of another phase or one not matching its planet
+    pub fn check_ids(&self, num: usize) -> Vec<String> {
+        self.planets()
+            .flat_map(|(alignment, planet)| {
+                planet.missions.iter().filter_map(move |mission| {
+                    check_id(&mission.id, &mission.name, &planet.name, num, alignment)
+                })
+            })
+            .collect()
+    }
+
+    /// number of planets, including the bonus
+    /// planet unless it is locked
     pub fn num(&self) -> usize {
-        match self.bonus {
+        match self.unlocked_bonus() {
             Some(_) => 4,
             None => 3,
         }
     }
+
+    /// whether the star thresholds of any planet are known,
+    /// without them no stars can be planned
+    pub fn has_thresholds(&self) -> bool {
+        self.planets()
+            .any(|(_, planet)| planet.thresholds.is_some())
+    }
+
+    /// the bonus planet unless it is locked
+    pub fn unlocked_bonus(&self) -> Option<&Planet> {
+        self.bonus.as_ref().filter(|_| !self.locked)
+    }
+
+    /// column of the bonus planet, it is placed in front of
+    /// the alignment of the mission unlocking it
+    fn bonus_column(&self) -> Option<usize> {
+        let bonus = self.unlocked_bonus()?;
+        let alignment = bonus
+            .unlock
+            .as_deref()
+            .and_then(|id| id.parse::<MissionId>().ok())
+            .map(|id| id.alignment);
+        Some(match alignment {
+            Some(Alignment::Dark) => 0,
+            Some(Alignment::Mixed) => 1,
+            Some(Alignment::Light) => 2,
+            Some(Alignment::Bonus) | None => 3,
+        })
+    }
+
+    fn resolve_tags(&mut self, units: &Units) {
+        let planets = [&mut self.dark, &mut self.mixed, &mut self.light];
+        for planet in planets.into_iter().chain(self.bonus.as_mut()) {
+            for slot in planet.missions.iter_mut().flat_map(|m| &mut m.team) {
+                slot.resolve(units);
+            }
+        }
+    }
+
+    fn inherit_relic(&mut self) {
+        self.dark.inherit_relic();
+        self.mixed.inherit_relic();
+        self.light.inherit_relic();
+        if let Some(bonus) = &mut self.bonus {
+            bonus.inherit_relic();
+        }
+    }
 }
 
 impl<'a> Phase {
@@ -45,28 +136,22 @@ pub struct PhaseIterator<'a> {
     idx: usize,
 }
 
-impl<'a> PhaseIterator<'a> {
-    fn ret(&mut self, next: &'a Planet) -> Option<&'a Planet> {
-        if self.idx > 3 {
-            return None;
-        }
-        self.idx += 1;
-        Some(next)
-    }
-}
-
 impl<'a> Iterator for PhaseIterator<'a> {
     type Item = &'a Planet;
     fn next(&mut self) -> Option<Self::Item> {
-        match (self.idx, &self.phase.bonus) {
-            (0, None | Some(_)) => self.ret(&self.phase.dark),
-            (1, None) => self.ret(&self.phase.mixed),
-            (2, None) | (3, Some(_)) => self.ret(&self.phase.light),
-            (1, Some(bonus)) if bonus.is_mandalore() => self.ret(bonus),
-            (2, Some(bonus)) if bonus.is_mandalore() => self.ret(&self.phase.mixed),
-            (1, Some(bonus)) if bonus.is_zeffo() => self.ret(&self.phase.mixed),
-            (2, Some(bonus)) if bonus.is_zeffo() => self.ret(bonus),
-            _ => None,
-        }
+        let phase = self.phase;
+        let idx = match phase.bonus_column() {
+            Some(column) if column == self.idx => {
+                self.idx += 1;
+                return phase.bonus.as_ref();
+            }
+            Some(column) if column < self.idx => self.idx - 1,
+            _ => self.idx,
+        };
+        let planet = [&phase.dark, &phase.mixed, &phase.light]
+            .get(idx)
+            .copied()?;
+        self.idx += 1;
+        Some(planet)
     }
 }
@@ -1,21 +1,52 @@
-use crate::Mission;
+use crate::{Mission, api::teams::check::check_relic};
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Planet {
     pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<Vec<String>>,
+    /// territory points required for one, two
+    /// and three stars
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thresholds: Option<[u64; 3]>,
+    /// relic requirement of all missions on this
+    /// planet unless a mission overrides it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relic: Option<u8>,
+    /// ID of the special mission unlocking
+    /// this bonus planet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unlock: Option<String>,
     #[serde(rename = "mission")]
     pub missions: Vec<Mission>,
 }
 
 impl Planet {
-    pub fn is_mandalore(&self) -> bool {
-        self.name.eq_ignore_ascii_case("Mandalore")
+    /// missions with an explicit relic requirement lower than
+    /// the one of this planet, which is the minimum of all its
+    /// missions, and fleets with one
+    pub fn relic_mismatches(&self) -> Vec<String> {
+        self.missions
+            .iter()
+            .filter_map(|mission| {
+                check_relic(
+                    &mission.id,
+                    &mission.name,
+                    &self.name,
+                    mission.relic,
+                    self.relic,
+                )
+            })
+            .collect()
     }
 
-    pub fn is_zeffo(&self) -> bool {
-        self.name.eq_ignore_ascii_case("Zeffo")
+    /// lets all missions inherit the relic requirement of this planet
+    pub(crate) fn inherit_relic(&mut self) {
+        for mission in &mut self.missions {
+            mission.planet_relic = self.relic;
+        }
     }
 }
@@ -0,0 +1,162 @@
+use crate::{Mission, MissionKind, Planet};
+
+/// what the search field understands
+///
+/// - a mission ID prefix, e.g. `P2DCM`
+/// - a phase and the first letter of a planet, e.g. `P3Z` for Zeffo,
+///   `D`, `M`, `L` and `B` are alignments unless no mission has it
+/// - a kind of mission in all phases: `CM`, `SM` or `F`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MissionQuery {
+    Prefix(String),
+    Planet {
+        phase: usize,
+        initial: char,
+        /// prefix of the mission kind, e.g. `CM`
+        rest: String,
+    },
+    Combat,
+    Special,
+    Fleet,
+}
+
+impl MissionQuery {
+    pub fn new(s: &str) -> Self {
+        let s = s.trim().to_ascii_lowercase();
+        match s.as_str() {
+            "cm" => return Self::Combat,
+            "sm" => return Self::Special,
+            "f" => return Self::Fleet,
+            _ => {}
+        }
+
+        let planet = s.strip_prefix('p').and_then(|rest| {
+            let digits = rest.chars().take_while(char::is_ascii_digit).count();
+            let (phase, rest) = rest.split_at(digits);
+            let mut chars = rest.chars();
+            let initial = chars.next()?;
+            Some(Self::Planet {
+                phase: phase.parse().ok()?,
+                initial,
+                rest: chars.as_str().to_owned(),
+            })
+        });
+        planet.unwrap_or(Self::Prefix(s))
+    }
+
+    /// whether `mission` on `planet` of phase `num` matches
+    pub fn matches(&self, num: usize, planet: &Planet, mission: &Mission) -> bool {
+        let id = mission.id.to_ascii_lowercase();
+        let kind = || mission.mission_id().ok().map(|id| id.kind);
+        match self {
+            Self::Prefix(prefix) => !prefix.is_empty() && id.starts_with(prefix),
+            Self::Planet {
+                phase,
+                initial,
+                rest,
+            } => {
+                let by_alignment = id.starts_with(&format!("p{phase}{initial}{rest}"));
+                let is_alignment = matches!(initial, 'd' | 'm' | 'l' | 'b');
+                by_alignment || !is_alignment && self.matches_name(num, planet, mission)
+            }
+            Self::Combat => kind().is_some_and(|k| k.is_combat()),
+            Self::Special => kind() == Some(MissionKind::Special),
+            Self::Fleet => kind() == Some(MissionKind::Fleet),
+        }
+    }
+
+    /// whether `mission` is on a planet of phase `num` whose name starts with
+    /// the initial, for alignment initials no mission matched otherwise
+    pub fn matches_name(&self, num: usize, planet: &Planet, mission: &Mission) -> bool {
+        let Self::Planet {
+            phase,
+            initial,
+            rest,
+        } = self
+        else {
+            return false;
+        };
+        *phase == num
+            && planet.name.to_ascii_lowercase().starts_with(*initial)
+            && mission
+                .mission_id()
+                .is_ok_and(|id| id.kind.to_string().to_ascii_lowercase().starts_with(rest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Teams, Units};
+
+    #[test]
+    fn queries_are_parsed() {
+        assert_eq!(MissionQuery::new(" CM "), MissionQuery::Combat, "kind");
+        assert_eq!(MissionQuery::new("sm"), MissionQuery::Special, "kind");
+        assert_eq!(MissionQuery::new("F"), MissionQuery::Fleet, "kind");
+        assert_eq!(
+            MissionQuery::new("P3Z"),
+            MissionQuery::Planet {
+                phase: 3,
+                initial: 'z',
+                rest: String::new(),
+            },
+            "phase and planet"
+        );
+        assert_eq!(
+            MissionQuery::new("P2DCM"),
+            MissionQuery::Planet {
+                phase: 2,
+                initial: 'd',
+                rest: "cm".to_owned(),
+            },
+            "phase, planet and kind"
+        );
+        assert_eq!(
+            MissionQuery::new("Reva"),
+            MissionQuery::Prefix("reva".to_owned()),
+            "anything else"
+        );
+    }
+
+    #[test]
+    fn partial_queries_find_missions() {
+        let teams = Teams::load(&Units::load().expect("units load")).expect("data files load");
+        let ids = |s: &str| {
+            teams
+                .search(s)
+                .map(|(_, _, mission)| mission.id.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let zeffo = ids("P3Z");
+        assert!(!zeffo.is_empty(), "Zeffo is the bonus planet of phase 3");
+        assert!(
+            zeffo.iter().all(|id| id.starts_with("P3B")),
+            "only Zeffo: {zeffo:?}"
+        );
+        assert_eq!(ids("P3ZCM"), ["P3BCM"], "kind on a planet");
+        assert_eq!(ids("P3BCM"), ["P3BCM"], "by alignment");
+
+        let combat = teams
+            .search("CM")
+            .map(|(_, _, mission)| mission)
+            .collect::<Vec<_>>();
+        assert!(
+            combat.len() > 6,
+            "combat missions of all phases: {}",
+            combat.len()
+        );
+        assert!(
+            combat
+                .iter()
+                .all(|mission| mission.mission_id().is_ok_and(|id| id.kind.is_combat())),
+            "only combat missions"
+        );
+        assert!(
+            combat.iter().any(|mission| mission.id == "P3MFS"),
+            "named combat missions are included"
+        );
+        assert!(ids("").is_empty(), "an empty query finds nothing");
+    }
+}
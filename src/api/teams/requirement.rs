@@ -0,0 +1,75 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Mission, Omicron};
+
+/// extra requirements of a single slot of a team
+#[derive(Debug, Serialize, Deserialize, Clone, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Requirement {
+    /// index of the team slot these requirements apply
+    /// to, starting at 0, whichever unit fills it
+    pub slot: usize,
+    /// minimum relic of this unit, replaces
+    /// the one of the mission
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relic: Option<u8>,
+    /// required zetas in the same notation
+    /// as omicrons, e.g. `"l"` or `"s1"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zetas: Option<Vec<Omicron>>,
+    /// whether the ultimate ability is required
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub ultimate: bool,
+    /// minimum speed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speed: Option<u32>,
+}
+
+impl Requirement {
+    /// compact form shown on the unit icon, e.g. `R8 Z2 U 300`
+    pub fn badge(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(relic) = self.relic {
+            parts.push(format!("R{relic}"));
+        }
+        if let Some(zetas) = self.zetas.as_ref().filter(|z| !z.is_empty()) {
+            parts.push(format!("Z{}", zetas.len()));
+        }
+        if self.ultimate {
+            parts.push("U".to_owned());
+        }
+        if let Some(speed) = self.speed {
+            parts.push(speed.to_string());
+        }
+        parts.join(" ")
+    }
+}
+
+/// e.g. `Relic 8+, Zeta Lead, Ultimate, Speed 300+`
+impl Display for Requirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(relic) = self.relic {
+            parts.push(format!("Relic {relic}+"));
+        }
+        for zeta in self.zetas.iter().flatten() {
+            parts.push(format!("Zeta {zeta}"));
+        }
+        if self.ultimate {
+            parts.push("Ultimate".to_owned());
+        }
+        if let Some(speed) = self.speed {
+            parts.push(format!("Speed {speed}+"));
+        }
+        f.write_str(&parts.join(", "))
+    }
+}
+
+impl Mission {
+    /// extra requirements of the team slot at `slot`
+    pub fn requirement(&self, slot: usize) -> Option<&Requirement> {
+        self.requirements.iter().flatten().find(|r| r.slot == slot)
+    }
+}
@@ -0,0 +1,130 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Mission, Phase, Planet, Units};
+
+/// something obtained by completing a mission
+///
+/// written as e.g. `{ type = "shards", unit = "merrin", amount = 5 }`
+#[derive(Debug, Serialize, Deserialize, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Reward {
+    /// territory points for each completed wave
+    Points { waves: Vec<u64> },
+    /// shards of the unit with this ID
+    Shards {
+        unit: String,
+        /// left out if unknown
+        #[serde(skip_serializing_if = "Option::is_none")]
+        amount: Option<u32>,
+    },
+    /// guild currency, e.g. Guild Event Tokens
+    Currency {
+        name: String,
+        /// left out if unknown
+        #[serde(skip_serializing_if = "Option::is_none")]
+        amount: Option<u32>,
+    },
+}
+
+impl Reward {
+    /// e.g. `5 Merrin shards`
+    pub fn label(&self, units: &Units) -> String {
+        match self {
+            Self::Points { waves } => {
+                format!("{} territory points", waves.iter().sum::<u64>())
+            }
+            Self::Shards { unit, amount } => {
+                with_amount(*amount, &format!("{} shards", units.get(unit).name))
+            }
+            Self::Currency { name, amount } => with_amount(*amount, name),
+        }
+    }
+}
+
+/// e.g. `5 Merrin shards`, or just `Merrin shards` if the amount is unknown
+fn with_amount(amount: Option<u32>, what: &str) -> String {
+    amount.map_or_else(|| what.to_owned(), |amount| format!("{amount} {what}"))
+}
+
+impl Mission {
+    /// territory points for completing all waves
+    pub fn points(&self) -> Option<u64> {
+        self.rewards
+            .iter()
+            .flatten()
+            .find_map(|reward| match reward {
+                Reward::Points { waves } => Some(waves.iter().sum()),
+                _ => None,
+            })
+    }
+
+    /// labels of all rewards including the planet it unlocks
+    pub fn reward_labels(&self, units: &Units) -> Vec<String> {
+        self.rewards
+            .iter()
+            .flatten()
+            .map(|reward| reward.label(units))
+            .chain(
+                self.unlocks
+                    .iter()
+                    .map(|planet| format!("unlocks {planet}")),
+            )
+            .collect()
+    }
+}
+
+impl Phase {
+    /// missions giving anything besides territory points
+    ///
+    /// alternative teams sharing an ID are only listed once
+    pub fn rewards(&self) -> Vec<(&Planet, &Mission)> {
+        let mut rewards: Vec<(&Planet, &Mission)> = Vec::new();
+        for planet in self {
+            for mission in &planet.missions {
+                let special = mission.unlocks.is_some()
+                    || mission
+                        .rewards
+                        .iter()
+                        .flatten()
+                        .any(|reward| !matches!(reward, Reward::Points { .. }));
+                if special && !rewards.iter().any(|(_, m)| m.id == mission.id) {
+                    rewards.push((planet, mission));
+                }
+            }
+        }
+        rewards
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Teams;
+
+    #[test]
+    fn special_missions_list_their_rewards() {
+        let units = Units::load().expect("units load");
+        let teams = Teams::load(&units).expect("data files load");
+        let rewards = teams.phases[2]
+            .rewards()
+            .into_iter()
+            .map(|(_, mission)| (mission.id.as_str(), mission.reward_labels(&units)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rewards,
+            [
+                ("P3DSM", vec!["Merrin shards".to_owned()]),
+                ("P3MSM", vec!["Third Sister shards".to_owned()]),
+                ("P3MM", vec!["unlocks Mandalore".to_owned()]),
+            ],
+            "alternative teams are listed once"
+        );
+
+        let known = Reward::Shards {
+            unit: "merrin".to_owned(),
+            amount: Some(5),
+        };
+        assert_eq!(known.label(&units), "5 Merrin shards", "known amount");
+    }
+}
@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Units;
+
+/// a single position of a team
+///
+/// written as a unit ID, a list of unit IDs or a tag,
+/// e.g. `"GI"`, `[ "7sis", "9sis" ]` or `{ tag = "inquisitorius" }`
+#[derive(Debug, Serialize, Deserialize, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum Slot {
+    /// a single unit, empty if the slot is unavailable
+    /// and `[ph]` if it can be filled with anything
+    Unit(String),
+    /// any of these units, the first one is preferred
+    Choice(Vec<String>),
+    /// any unit with this tag
+    Tag {
+        tag: String,
+        /// IDs of all units with the tag, filled in by [`crate::Phase::parse`]
+        #[serde(skip)]
+        units: Vec<String>,
+    },
+}
+
+impl Slot {
+    /// IDs of all units fitting this slot, the preferred one first
+    pub fn options(&self) -> &[String] {
+        match self {
+            Self::Unit(id) => std::slice::from_ref(id),
+            Self::Choice(ids) => ids,
+            Self::Tag { units, .. } => units,
+        }
+    }
+
+    /// the preferred unit ID, empty if there is none
+    pub fn first(&self) -> &str {
+        self.options().first().map_or("", String::as_str)
+    }
+
+    /// whether the unit `id` may be used in this slot
+    pub fn accepts(&self, id: &str) -> bool {
+        self.options().iter().any(|o| o.eq_ignore_ascii_case(id))
+    }
+
+    pub fn is_unavailable(&self) -> bool {
+        matches!(self, Self::Unit(id) if id.is_empty())
+    }
+
+    pub fn is_placeholder(&self) -> bool {
+        matches!(self, Self::Unit(id) if id.eq_ignore_ascii_case("[ph]"))
+    }
+
+    /// unavailable or a placeholder, not filled by a specific unit
+    pub fn is_open(&self) -> bool {
+        self.is_unavailable() || self.is_placeholder()
+    }
+
+    /// e.g. `Seventh Sister / Ninth Sister` or `any inquisitorius`
+    pub fn name(&self, units: &Units) -> String {
+        match self {
+            Self::Unit(id) => units.get(id).name,
+            Self::Choice(ids) => ids
+                .iter()
+                .map(|id| units.get(id).name)
+                .collect::<Vec<_>>()
+                .join(" / "),
+            Self::Tag { tag, .. } => format!("any {tag}"),
+        }
+    }
+
+    /// looks up the units of a tag slot
+    pub(crate) fn resolve(&mut self, all: &Units) {
+        if let Self::Tag { tag, units } = self {
+            *units = all
+                .data
+                .iter()
+                .filter(|unit| unit.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+                .map(|unit| unit.id.clone())
+                .collect();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Team {
+        team: Vec<Slot>,
+    }
+
+    #[test]
+    fn slots_round_trip() {
+        let team: Team = toml::from_str(
+            r#"team = [ "GI", [ "7sis", "9sis" ], { tag = "inquisitorius" }, "", "[ph]" ]"#,
+        )
+        .expect("valid team");
+        let expected = [
+            Slot::Unit("GI".to_owned()),
+            Slot::Choice(vec!["7sis".to_owned(), "9sis".to_owned()]),
+            Slot::Tag {
+                tag: "inquisitorius".to_owned(),
+                units: Vec::new(),
+            },
+            Slot::Unit(String::new()),
+            Slot::Unit("[ph]".to_owned()),
+        ];
+        assert_eq!(team.team, expected, "each form maps onto its variant");
+
+        let written = toml::to_string(&team).expect("serializable");
+        let read: Team = toml::from_str(&written).expect("own output parses");
+        assert_eq!(read.team, expected, "unchanged by a round trip: {written}");
+    }
+}
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Mission, Reward};
+
+/// waves of a mission without wave or point data
+pub const DEFAULT_WAVES: usize = 4;
+
+/// enemies of a single wave of a mission
+#[derive(Debug, Serialize, Deserialize, Clone, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Wave {
+    /// unit IDs of the enemies, the
+    /// leader first
+    ///
+    /// may only list the enemies worth knowing
+    /// about, or none if the wave is unknown
+    pub enemies: Vec<String>,
+    /// effects only active during this wave
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modifiers: Option<Vec<String>>,
+}
+
+impl Mission {
+    /// number of waves from the points per wave
+    ///
+    /// the enemies may stop at the last wave worth knowing
+    /// about, so they only raise it above [`DEFAULT_WAVES`]
+    pub fn wave_count(&self) -> usize {
+        self.rewards
+            .iter()
+            .flatten()
+            .find_map(|reward| match reward {
+                Reward::Points { waves } => Some(waves.len()),
+                _ => None,
+            })
+            .unwrap_or_else(|| {
+                self.waves
+                    .as_ref()
+                    .map_or(DEFAULT_WAVES, |waves| waves.len().max(DEFAULT_WAVES))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::teams::test_phase;
+
+    const PHASE: &str = r#"
+Dark = { name = "Mustafar", mission = [] }
+Mixed = { name = "Corellia", mission = [] }
+
+[Light]
+name = "Coruscant"
+
+[[Light.mission]]
+id = "P1LCM1"
+name = "Unknown"
+team = []
+note = []
+
+[[Light.mission]]
+id = "P1LCM2"
+name = "Hint"
+team = []
+note = []
+waves = [ { enemies = [] }, { enemies = [ "tarkin" ] } ]
+
+[[Light.mission]]
+id = "P1LCM3"
+name = "Points"
+team = []
+note = []
+waves = [ { enemies = [ "ddk" ] } ]
+rewards = [ { type = "points", waves = [ 100, 200 ] } ]
+"#;
+
+    #[test]
+    fn wave_count_prefers_points() {
+        let phase = test_phase(PHASE);
+        let counts = phase
+            .light
+            .missions
+            .iter()
+            .map(|mission| (mission.name.as_str(), mission.wave_count()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            counts,
+            [("Unknown", 4), ("Hint", 4), ("Points", 2)],
+            "enemy hints do not lower the default"
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Mission, Planet};
+
+/// personal progress of the missions of a single TB instance
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Tracker {
+    /// the TB instance this progress belongs to,
+    /// e.g. the start date of the TB
+    pub instance: String,
+    /// progress keyed by mission ID
+    pub missions: BTreeMap<String, Progress>,
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Progress {
+    pub status: Status,
+    /// number of waves achieved
+    pub waves: u8,
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Status {
+    #[default]
+    Open,
+    Done,
+    Failed,
+    Skipped,
+}
+
+impl Status {
+    pub const ALL: [Self; 4] = [Self::Open, Self::Done, Self::Failed, Self::Skipped];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Open => "Open",
+            Self::Done => "Done",
+            Self::Failed => "Failed",
+            Self::Skipped => "Skipped",
+        }
+    }
+}
+
+impl Tracker {
+    pub fn new(instance: &str) -> Self {
+        Self {
+            instance: instance.to_owned(),
+            missions: BTreeMap::new(),
+        }
+    }
+
+    /// storage key of the tracker of `instance`
+    pub fn key(instance: &str) -> String {
+        format!("tracker/{instance}")
+    }
+
+    pub fn get(&self, mission: &Mission) -> Progress {
+        self.missions.get(&mission.id).copied().unwrap_or_default()
+    }
+
+    pub fn set(&mut self, mission: &Mission, progress: Progress) {
+        if progress == Progress::default() {
+            self.missions.remove(&mission.id);
+        } else {
+            self.missions.insert(mission.id.clone(), progress);
+        }
+    }
+
+    /// number of finished (done, failed or skipped) missions
+    /// and the total number of missions of `planet`
+    ///
+    /// missions sharing an ID count as a single mission
+    pub fn planet_progress(&self, planet: &Planet) -> (usize, usize) {
+        let mut ids = planet.missions.iter().map(|m| &m.id).collect::<Vec<_>>();
+        ids.sort();
+        ids.dedup();
+
+        let finished = ids
+            .iter()
+            .filter(|id| {
+                self.missions
+                    .get(id.as_str())
+                    .is_some_and(|p| p.status != Status::Open)
+            })
+            .count();
+
+        (finished, ids.len())
+    }
+}
@@ -1,19 +1,26 @@
 mod unit;
+mod unit_id;
 
 pub use unit::Unit;
+pub use unit_id::UnitId;
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
+use crate::{LoadError, api::load::parse};
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Units {
     #[serde(rename = "Unit")]
     pub data: Vec<Unit>,
 }
 
 impl Units {
-    pub fn load() -> Self {
-        toml::from_slice(include_bytes!("../../../assets/data/Units.toml"))
-            .expect("failed to load units")
+    pub fn load() -> Result<Self, LoadError> {
+        parse(
+            "Units.toml",
+            include_bytes!("../../../assets/data/Units.toml"),
+        )
     }
 
     pub fn get(&self, id: &str) -> Unit {
@@ -30,4 +37,67 @@ impl Units {
         }
         Unit::missing()
     }
+
+    /// finds a unit by its ID, name or one of its aliases
+    pub fn find(&self, s: &str) -> Option<&Unit> {
+        let s = s.trim();
+        self.data
+            .iter()
+            .find(|u| u.id.eq_ignore_ascii_case(s))
+            .or_else(|| {
+                self.data.iter().find(|u| {
+                    u.name.eq_ignore_ascii_case(s)
+                        || u.aliases.iter().any(|a| a.eq_ignore_ascii_case(s))
+                })
+            })
+    }
+
+    /// finds the unit of a game `base_id`, units without a
+    /// known base ID match if their ID equals it
+    pub fn by_base_id(&self, base_id: &str) -> Option<&Unit> {
+        self.data
+            .iter()
+            .find(|u| u.base_id.as_deref() == Some(base_id))
+            .or_else(|| {
+                self.data
+                    .iter()
+                    .find(|u| u.base_id.is_none() && u.id.eq_ignore_ascii_case(base_id))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn units_are_found_by_id_name_or_alias() {
+        let units = Units::load().expect("units load");
+        let found = ["REY", "Rey", "GL Rey", " gl rey ", "Third Sister", "nobody"]
+            .map(|s| units.find(s).map(|unit| unit.id.as_str()));
+        assert_eq!(
+            found,
+            [
+                Some("rey"),
+                Some("rey"),
+                Some("rey"),
+                Some("rey"),
+                Some("reva"),
+                None
+            ],
+            "case insensitive and trimmed"
+        );
+    }
+
+    #[test]
+    fn units_are_found_by_base_id() {
+        let units = Units::load().expect("units load");
+        let found = ["GLREY", "REY", "THIRDSISTER"]
+            .map(|s| units.by_base_id(s).map(|unit| unit.id.as_str()));
+        assert_eq!(
+            found,
+            [Some("rey"), Some("stickRey"), Some("reva")],
+            "exact"
+        );
+    }
 }
@@ -3,11 +3,21 @@ use serde::{Deserialize, Serialize};
 use crate::Ability;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Unit {
     pub id: String,
     pub name: String,
     pub image: String,
     pub omicrons: Vec<Ability>,
+    /// ID of the unit in the game data, e.g. `BOSSK`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_id: Option<String>,
+    /// other names the unit is known by, e.g. `GL Rey`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    /// factions and roles used by team slots, e.g. `inquisitorius`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
 }
 
 impl Unit {
@@ -18,6 +28,9 @@ impl Unit {
             name: name.to_owned(),
             image: image.to_owned(),
             omicrons: vec![],
+            base_id: None,
+            aliases: vec![],
+            tags: vec![],
         }
     }
 
@@ -62,6 +75,9 @@ impl Unit {
             name: "Unknown Unit".to_owned(),
             image: "icon-missing.png".to_owned(),
             omicrons: vec![],
+            base_id: None,
+            aliases: vec![],
+            tags: vec![],
         }
     }
 
@@ -71,6 +87,9 @@ impl Unit {
             name: "open spot".to_owned(),
             image: "icon-placeholder.png".to_owned(),
             omicrons: vec![],
+            base_id: None,
+            aliases: vec![],
+            tags: vec![],
         }
     }
 
@@ -80,6 +99,9 @@ impl Unit {
             name: "unavailable".to_owned(),
             image: "icon-unavailable.png".to_owned(),
             omicrons: vec![],
+            base_id: None,
+            aliases: vec![],
+            tags: vec![],
         }
     }
 
@@ -89,6 +111,9 @@ impl Unit {
             name: "forgot to add all spots in PhaseX.toml".to_owned(),
             image: "icon-missing.png".to_owned(),
             omicrons: vec![],
+            base_id: None,
+            aliases: vec![],
+            tags: vec![],
         }
     }
 }
@@ -0,0 +1,21 @@
+use std::{fmt::Display, str::FromStr};
+
+include!(concat!(env!("OUT_DIR"), "/unit_id.rs"));
+
+impl Display for UnitId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for UnitId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|id| id.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("unknown unit `{s}`"))
+    }
+}
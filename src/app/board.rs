@@ -0,0 +1,184 @@
+use crate::{Assignments, Mission, Planet};
+
+use super::App;
+
+pub(super) fn restore(storage: Option<&dyn eframe::Storage>) -> Assignments {
+    storage
+        .and_then(|storage| eframe::get_value(storage, Assignments::KEY))
+        .unwrap_or_default()
+}
+
+pub(super) fn persist(storage: &mut dyn eframe::Storage, assignments: &Assignments) {
+    eframe::set_value(storage, Assignments::KEY, assignments);
+}
+
+impl App {
+    /// assignment board of a phase: the mission cards
+    /// together with the players assigned to them
+    pub(super) fn render_board(&self, ui: &mut egui::Ui, idx: usize) {
+        let phase = &self.teams.phases[idx];
+        let num = idx + 1;
+
+        self.render_board_toolbar(ui, idx);
+
+        let unassigned = self
+            .assignments
+            .borrow()
+            .unassigned(phase)
+            .iter()
+            .map(|m| m.id.clone())
+            .collect::<Vec<_>>();
+        ui.label(
+            egui::RichText::new(if unassigned.is_empty() {
+                format!("All missions of Phase {num} are assigned.")
+            } else {
+                format!("Unassigned: {}", unassigned.join(", "))
+            })
+            .size(self.note_font_size()),
+        );
+
+        if self.is_portrait() {
+            ui.vertical(|ui| {
+                for planet in phase {
+                    self.render_board_planet(ui, planet);
+                }
+            });
+        } else {
+            ui.columns(phase.num(), |ui| {
+                for (col, planet) in phase.iter().enumerate() {
+                    self.render_board_planet(&mut ui[col], planet);
+                }
+            });
+        }
+    }
+
+    fn render_board_toolbar(&self, ui: &mut egui::Ui, idx: usize) {
+        let phase = &self.teams.phases[idx];
+        let num = idx + 1;
+        let error_id = ui.id().with("assignments-error");
+
+        ui.horizontal(|ui| {
+            if ui
+                .button("Copy order list")
+                .on_hover_text("Copy the assignments of this phase as text")
+                .clicked()
+            {
+                ui.ctx()
+                    .copy_text(self.assignments.borrow().to_order_list(phase, num));
+            }
+            if ui.button("Export").clicked() {
+                match self.assignments.borrow().to_toml() {
+                    Ok(toml) => {
+                        self.download("Assignments.toml", "application/toml", &toml);
+                        ui.data_mut(|d| d.remove::<String>(error_id));
+                    }
+                    Err(err) => {
+                        log::error!("failed to export assignments: {err}");
+                        ui.data_mut(|d| {
+                            d.insert_temp(error_id, format!("Export failed: {err}"));
+                        });
+                    }
+                }
+            }
+            if ui.button("Clear phase").clicked() {
+                self.assignments.borrow_mut().clear_phase(phase);
+            }
+        });
+
+        ui.collapsing("Import", |ui| {
+            let id = ui.id().with("assignments-import");
+            let mut text = ui.data_mut(|d| d.get_temp::<String>(id).unwrap_or_default());
+            ui.add(
+                egui::TextEdit::multiline(&mut text)
+                    .hint_text("paste an exported Assignments.toml or an order list")
+                    .desired_rows(4),
+            );
+            if ui.button("Import").clicked() {
+                let imported = match Assignments::from_toml(&text) {
+                    Ok(assignments) => Ok(assignments),
+                    // an order list only replaces this phase
+                    Err(err) => Assignments::from_order_list(&text)
+                        .map(|list| {
+                            let mut assignments = self.assignments.borrow().clone();
+                            assignments.clear_phase(phase);
+                            assignments.missions.extend(list.missions);
+                            assignments
+                        })
+                        .ok_or(err),
+                };
+                match imported {
+                    Ok(assignments) => {
+                        self.assignments.replace(assignments);
+                        text.clear();
+                        ui.data_mut(|d| d.remove::<String>(error_id));
+                    }
+                    Err(err) => {
+                        log::error!("failed to import assignments: {err}");
+                        ui.data_mut(|d| {
+                            d.insert_temp(error_id, format!("Import failed: {err}"));
+                        });
+                    }
+                }
+            }
+            ui.data_mut(|d| d.insert_temp(id, text));
+        });
+
+        if let Some(err) = ui.data(|d| d.get_temp::<String>(error_id)) {
+            ui.colored_label(ui.visuals().error_fg_color, err);
+        }
+    }
+
+    fn render_board_planet(&self, ui: &mut egui::Ui, planet: &Planet) {
+        ui.vertical(|ui| {
+            ui.vertical_centered(|ui| {
+                ui.label(
+                    egui::RichText::new(&planet.name)
+                        .strong()
+                        .size(self.planet_font_size()),
+                );
+            });
+            for mission in &planet.missions {
+                ui.push_id(mission, |ui| {
+                    self.render_mission(ui, mission);
+                    self.render_assignees(ui, mission);
+                });
+            }
+        });
+    }
+
+    /// the players assigned to `mission` and a field to add more
+    fn render_assignees(&self, ui: &mut egui::Ui, mission: &Mission) {
+        let size = self.note_font_size();
+        ui.group(|ui| {
+            ui.horizontal_wrapped(|ui| {
+                ui.label(egui::RichText::new("Assigned:").size(size).strong());
+
+                let players = self.assignments.borrow().players(mission).to_vec();
+                for player in players {
+                    if ui
+                        .button(egui::RichText::new(format!("{player} ✖")).size(size))
+                        .on_hover_text("Remove this player")
+                        .clicked()
+                    {
+                        self.assignments.borrow_mut().unassign(mission, &player);
+                    }
+                }
+
+                let id = ui.id().with("new-assignee");
+                let mut name = ui.data_mut(|d| d.get_temp::<String>(id).unwrap_or_default());
+                let res = ui.add(
+                    egui::TextEdit::singleline(&mut name)
+                        .hint_text("player")
+                        .desired_width(100.),
+                );
+                if (res.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)))
+                    || ui.button("Add").clicked()
+                {
+                    self.assignments.borrow_mut().assign(mission, &name);
+                    name.clear();
+                }
+                ui.data_mut(|d| d.insert_temp(id, name));
+            });
+        });
+    }
+}
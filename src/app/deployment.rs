@@ -0,0 +1,41 @@
+use crate::{Deployment, PlanetDeployment};
+
+use super::App;
+
+impl App {
+    pub(super) fn render_deployment(&self, ui: &mut egui::Ui, deployment: &Deployment) {
+        ui.label(
+            egui::RichText::new(format!(
+                "Deployable GP: {} ({} not needed for any star)",
+                gp(deployment.available),
+                gp(deployment.leftover)
+            ))
+            .size(self.note_font_size()),
+        );
+    }
+
+    /// projected stars and GP to deploy below the planet name
+    pub(super) fn render_planet_deployment(&self, ui: &mut egui::Ui, planet: &PlanetDeployment) {
+        let size = self.note_font_size();
+        if planet.thresholds.is_none() {
+            ui.label(
+                egui::RichText::new("star thresholds unknown")
+                    .size(size)
+                    .weak(),
+            );
+            return;
+        }
+        let stars = format!(
+            "{}{}",
+            "★".repeat(usize::from(planet.stars)),
+            "☆".repeat(3 - usize::from(planet.stars))
+        );
+        ui.label(egui::RichText::new(format!("{stars} deploy {}", gp(planet.deploy))).size(size));
+    }
+}
+
+fn gp(gp: u64) -> String {
+    #[expect(clippy::cast_precision_loss, reason = "only used for display")]
+    let millions = gp as f64 / 1_000_000.0;
+    format!("{millions:.1}M")
+}
@@ -0,0 +1,891 @@
+#![cfg(target_arch = "wasm32")]
+
+mod board;
+mod deployment;
+mod operations;
+mod rewards;
+mod roster;
+mod tracker;
+mod view;
+
+use std::{cell::RefCell, str::FromStr as _};
+
+use egui_commonmark::{CommonMarkCache, CommonMarkViewer};
+
+use crate::{
+    Assignments, Auto, Difficulty, Guild, LoadError, Mission, Omicron, Omicrons, Planet,
+    PlanetDeployment, Requirement, Resolution, Slot, Tab, Teams, Tracker, Unit, Units, Video,
+};
+
+use view::{Layout, View};
+
+const CAPITAL_SHIP_FACTOR: f32 = 1.5;
+const STARTING_LINEUP_FACTOR: f32 = 0.9;
+const REINFORCEMENT_FACTOR: f32 = 0.8;
+/// seconds each option of a slot is shown
+const SLOT_ROTATION: f64 = 1.5;
+
+const GREEN: egui::Color32 = egui::Color32::from_rgb(0x2e, 0x7d, 0x32);
+const AMBER: egui::Color32 = egui::Color32::from_rgb(0xb2, 0x6a, 0x00);
+const RED: egui::Color32 = egui::Color32::from_rgb(0xc6, 0x28, 0x28);
+
+pub struct App {
+    units: Units,
+    teams: Teams,
+    /// cached search results, layout and units
+    view: View,
+    search: String,
+    tab: Tab,
+    /// mission progress of the selected TB instance
+    tracker: RefCell<Tracker>,
+    /// TB instance currently being edited in the text field
+    instance: String,
+    /// players assigned to missions by officers
+    assignments: RefCell<Assignments>,
+    /// show the assignment board instead of the recommendation
+    board: bool,
+    /// hide missions which cannot be fully automated
+    full_auto: bool,
+    /// imported rosters of the guild
+    guild: Guild,
+    /// member whose plan is shown instead of the recommendation
+    player: Option<String>,
+
+    /// data files that failed to load, nothing but
+    /// these is shown if there are any
+    errors: Vec<LoadError>,
+
+    window: web_sys::Window,
+    origin: String,
+}
+
+impl App {
+    /// Called once before the first frame.
+    pub fn new(cc: &eframe::CreationContext<'_>, window: web_sys::Window) -> Self {
+        egui_extras::install_image_loaders(&cc.egui_ctx);
+
+        let tracker = tracker::restore(cc.storage);
+
+        let mut errors = Vec::new();
+        let units = Units::load().unwrap_or_else(|err| {
+            errors.push(err);
+            Units::default()
+        });
+        let teams = Teams::load(&units).unwrap_or_else(|err| {
+            errors.push(err);
+            Teams::default()
+        });
+        for err in &errors {
+            log::error!("failed to load data: {err}");
+        }
+        for problem in teams
+            .check_ids()
+            .into_iter()
+            .chain(teams.relic_mismatches())
+        {
+            log::warn!("inconsistent data: {problem}");
+        }
+
+        let view = View::new(&units, &resolution(&window));
+
+        Self {
+            units,
+            teams,
+            view,
+            errors,
+            search: Default::default(),
+            instance: tracker.instance.clone(),
+            tracker: RefCell::new(tracker),
+            assignments: RefCell::new(board::restore(cc.storage)),
+            board: false,
+            full_auto: false,
+            guild: roster::restore(cc.storage),
+            player: None,
+            tab: Tab::from_str(&window.location().hash().unwrap_or("1".to_owned()))
+                .unwrap_or_default(),
+            window,
+            origin: if cc.integration_info.web_info.location.url.contains("dev") {
+                cc.integration_info.web_info.location.origin.clone()
+            } else {
+                cc.integration_info
+                    .web_info
+                    .location
+                    .url
+                    .split('#')
+                    .next()
+                    .expect("split always has first")
+                    .to_owned()
+            },
+        }
+    }
+
+    fn character_icon_size(&self) -> egui::Vec2 {
+        egui::Vec2::splat(self.view.layout.character_icon_size)
+    }
+
+    fn planet_font_size(&self) -> f32 {
+        self.view.layout.planet_font_size
+    }
+
+    fn mission_font_size(&self) -> f32 {
+        self.view.layout.mission_font_size
+    }
+
+    fn note_font_size(&self) -> f32 {
+        self.view.layout.note_font_size
+    }
+
+    fn unit_font_size(&self) -> f32 {
+        self.view.layout.unit_font_size
+    }
+
+    fn is_portrait(&self) -> bool {
+        self.view.layout.portrait
+    }
+
+    fn render_phase(&self, ui: &mut egui::Ui, idx: usize) {
+        let phase = &self.teams.phases[idx];
+        self.render_export(ui, idx);
+        self.render_operations(ui, idx);
+        self.render_rewards(ui, idx);
+        let plans = self.phase_plans(idx);
+        // GP can only be planned once the star thresholds are known
+        let deployment = plans
+            .as_ref()
+            .filter(|_| phase.has_thresholds())
+            .map(|plans| &plans.deployment);
+        if let Some(deployment) = deployment {
+            self.render_deployment(ui, deployment);
+        }
+        let deploy =
+            |planet: &Planet| deployment.and_then(|deployment| deployment.planet(&planet.name));
+        if self.is_portrait() {
+            ui.vertical(|ui| {
+                for planet in phase {
+                    self.render_planet(ui, planet, deploy(planet));
+                }
+            });
+        } else {
+            ui.columns(phase.num(), |ui| {
+                for (col, planet) in phase.iter().enumerate() {
+                    self.render_planet(&mut ui[col], planet, deploy(planet));
+                }
+            });
+        }
+    }
+
+    /// lets the user hide a bonus planet which was not unlocked
+    fn render_bonus_toggle(&mut self, ui: &mut egui::Ui, idx: usize) {
+        let phase = &mut self.teams.phases[idx];
+        let Some(bonus) = &phase.bonus else {
+            return;
+        };
+        let hover = match &bonus.unlock {
+            Some(id) => format!("Unlocked by completing {id} in an earlier phase"),
+            None => "Show this bonus planet".to_owned(),
+        };
+        let mut unlocked = !phase.locked;
+        if ui
+            .checkbox(&mut unlocked, format!("{} unlocked", bonus.name))
+            .on_hover_text(hover)
+            .changed()
+        {
+            phase.locked = !unlocked;
+            self.view.clear_plans();
+            self.view.clear_discord();
+            self.view.clear_search();
+        }
+    }
+
+    fn render_export(&self, ui: &mut egui::Ui, idx: usize) {
+        let phase = &self.teams.phases[idx];
+        let num = idx + 1;
+        ui.horizontal(|ui| {
+            ui.label("Export:");
+            if ui.button("Markdown").clicked() {
+                self.download(
+                    &format!("Phase{num}.md"),
+                    "text/markdown",
+                    &phase.to_markdown(num, &self.units),
+                );
+            }
+            if ui.button("CSV").clicked() {
+                self.download(
+                    &format!("Phase{num}.csv"),
+                    "text/csv",
+                    &phase.to_csv(num, &self.units),
+                );
+            }
+            if ui.button("JSON").clicked() {
+                match phase.to_json(num, &self.units) {
+                    Ok(json) => {
+                        self.download(&format!("Phase{num}.json"), "application/json", &json);
+                    }
+                    Err(err) => log::error!("failed to export phase {num}: {err}"),
+                }
+            }
+        });
+        ui.horizontal_wrapped(|ui| {
+            ui.label("Discord:");
+            let messages = self
+                .view
+                .discord(idx, || phase.to_discord(num, &self.units));
+            let total = messages.len();
+            for (idx, message) in messages.iter().enumerate() {
+                if ui
+                    .button(format!("Copy {}/{total}", idx + 1))
+                    .on_hover_text("Copy this message to the clipboard to post it on Discord")
+                    .clicked()
+                {
+                    ui.ctx().copy_text(message.clone());
+                }
+            }
+        });
+    }
+
+    /// lets the browser download `content` as a file
+    fn download(&self, file_name: &str, mime: &str, content: &str) {
+        use eframe::wasm_bindgen::JsCast as _;
+
+        let result = (|| {
+            let parts = js_sys::Array::of1(&content.into());
+            let options = web_sys::BlobPropertyBag::new();
+            options.set_type(mime);
+            let blob = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options)?;
+            let url = web_sys::Url::create_object_url_with_blob(&blob)?;
+
+            let document = self.window.document().ok_or("missing document")?;
+            let anchor = document
+                .create_element("a")?
+                .dyn_into::<web_sys::HtmlAnchorElement>()?;
+            anchor.set_href(&url);
+            anchor.set_download(file_name);
+            anchor.click();
+
+            web_sys::Url::revoke_object_url(&url)
+        })();
+
+        if let Err(err) = result {
+            log::error!("failed to download {file_name}: {err:?}");
+        }
+    }
+
+    fn render_errors(&self, ui: &mut egui::Ui) {
+        ui.heading("Failed to load the team data");
+        ui.label("Please report this, the following data files are broken:");
+        for err in &self.errors {
+            ui.group(|ui| {
+                ui.label(egui::RichText::new(&err.file).strong());
+                if let Some((line, column)) = err.position {
+                    ui.label(format!("line {line}, column {column}"));
+                }
+                if let Some(key) = &err.key {
+                    ui.label(format!("key: {key}"));
+                }
+                ui.colored_label(ui.visuals().error_fg_color, &err.message);
+            });
+        }
+    }
+
+    fn render_planet(
+        &self,
+        ui: &mut egui::Ui,
+        planet: &Planet,
+        deployment: Option<&PlanetDeployment>,
+    ) {
+        let (finished, total) = self.tracker.borrow().planet_progress(planet);
+        ui.vertical(|ui| {
+            ui.vertical_centered(|ui| {
+                ui.label(
+                    egui::RichText::new(format!("{} ({finished}/{total})", planet.name))
+                        .strong()
+                        .size(self.planet_font_size()),
+                );
+                if let Some(deployment) = deployment {
+                    self.render_planet_deployment(ui, deployment);
+                }
+            });
+            if let Some(notes) = &planet.notes {
+                self.render_note(ui, notes);
+            }
+            for mission in planet
+                .missions
+                .iter()
+                .filter(|mission| !self.full_auto || mission.is_full_auto())
+            {
+                self.render_mission(ui, mission);
+            }
+        });
+    }
+
+    fn render_mission(&self, ui: &mut egui::Ui, mission: &Mission) {
+        ui.group(|ui| {
+            ui.vertical(|ui| {
+                ui.vertical_centered(|ui| {
+                    ui.label(
+                        egui::RichText::new(format!("{} ({})", mission.name, mission.id))
+                            .underline()
+                            .size(self.mission_font_size()),
+                    );
+                });
+
+                ui.horizontal(|ui| {
+                    if mission.omicrons.is_some() {
+                        ui.add_sized(
+                            self.character_icon_size() * 0.33,
+                            |ui: &mut egui::Ui| -> egui::Response {
+                                ui.add(egui::Image::new(format!(
+                                    "{}/assets/img/icon-omicron.webp",
+                                    self.origin
+                                )))
+                            },
+                        );
+                    }
+
+                    ui.label(
+                        egui::RichText::new(match mission.relic() {
+                            Some(relic) => format!("Relic: {relic}+"),
+                            None => "7* Stars".to_owned(),
+                        })
+                        .size(self.note_font_size()),
+                    );
+
+                    if let Some(auto) = mission.auto {
+                        let color = match auto {
+                            Auto::Full => GREEN,
+                            Auto::Partial => AMBER,
+                            Auto::Manual => RED,
+                        };
+                        ui.label(self.badge(auto.label(), color));
+                    }
+                    if let Some(difficulty) = mission.difficulty {
+                        let color = match difficulty {
+                            Difficulty::Easy => GREEN,
+                            Difficulty::Medium => AMBER,
+                            Difficulty::Hard => RED,
+                        };
+                        ui.label(self.badge(difficulty.label(), color));
+                    }
+                });
+
+                self.render_progress(ui, mission);
+
+                ui.separator();
+
+                ui.horizontal(|ui| {
+                    let missing = if !mission.is_fleet() {
+                        self.render_squad(
+                            ui,
+                            &mission.team,
+                            mission.omicrons.as_deref(),
+                            mission.requirements.as_deref(),
+                        )
+                    } else {
+                        self.render_fleet(ui, &mission.team)
+                    };
+                    self.missing_helper(missing, ui);
+                });
+
+                ui.separator();
+
+                self.render_note(ui, &mission.note);
+
+                if mission.waves.is_some() || mission.modifiers.is_some() {
+                    ui.separator();
+
+                    self.render_opponents(ui, mission);
+                }
+
+                if let Some(omicrons) = &mission.omicrons {
+                    ui.separator();
+
+                    self.render_omicron_list(ui, omicrons);
+                }
+
+                if let Some(videos) = &mission.videos {
+                    ui.separator();
+
+                    self.render_videos(ui, videos);
+                }
+
+                if let Some(modding) = &mission.modding {
+                    ui.separator();
+
+                    self.render_modding(ui, modding);
+                }
+            });
+        });
+    }
+
+    /// short colored label, e.g. next to the relic requirement
+    fn badge(&self, text: &str, color: egui::Color32) -> egui::RichText {
+        egui::RichText::new(format!(" {text} "))
+            .size(self.note_font_size())
+            .color(egui::Color32::WHITE)
+            .background_color(color)
+    }
+
+    fn render_note(&self, ui: &mut egui::Ui, notes: &[String]) {
+        let size = self.note_font_size();
+        ui.vertical(|ui| {
+            ui.label(
+                egui::RichText::new("Notes:")
+                    .size(size)
+                    .underline()
+                    .strong(),
+            );
+            ui.spacing_mut().item_spacing.y = 10.0;
+            for note in notes {
+                ui.label(egui::RichText::new(note).size(size));
+            }
+        });
+    }
+
+    /// mission modifiers and the enemies of each wave
+    fn render_opponents(&self, ui: &mut egui::Ui, mission: &Mission) {
+        let size = self.note_font_size();
+        egui::CollapsingHeader::new(
+            egui::RichText::new("Opponents")
+                .size(size)
+                .underline()
+                .strong(),
+        )
+        .id_salt(("opponents", &mission.id, &mission.team))
+        .show(ui, |ui| {
+            for modifier in mission.modifiers.iter().flatten() {
+                ui.label(egui::RichText::new(format!("\t- {modifier}")).size(size));
+            }
+            for (idx, wave) in mission.waves.iter().flatten().enumerate() {
+                if wave.enemies.is_empty() && wave.modifiers.is_none() {
+                    continue;
+                }
+                ui.label(egui::RichText::new(format!("Wave {}", idx + 1)).size(size));
+                for modifier in wave.modifiers.iter().flatten() {
+                    ui.label(egui::RichText::new(format!("\t- {modifier}")).size(size));
+                }
+                ui.horizontal_wrapped(|ui| {
+                    for enemy in &wave.enemies {
+                        let unit = self.view.unit(enemy);
+                        self.render_unit(ui, unit, self.character_icon_size() * 0.66, None, None);
+                    }
+                });
+            }
+        });
+    }
+
+    fn render_omicron_list(&self, ui: &mut egui::Ui, omicrons: &[Omicrons]) {
+        let size = self.note_font_size();
+        ui.vertical(|ui| {
+            ui.label(
+                egui::RichText::new("Required Omicrons:")
+                    .size(size)
+                    .underline()
+                    .strong(),
+            );
+            for omicron in omicrons {
+                let unit = self.view.unit(&omicron.unit);
+                for omi in &omicron.omis {
+                    let ability = unit.get_omicron(&omi.to_id());
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "\t- {}: {} \"{}\"",
+                            unit.name, omi, ability.name
+                        ))
+                        .size(size),
+                    );
+                }
+            }
+        });
+    }
+
+    fn render_videos(&self, ui: &mut egui::Ui, videos: &[Video]) {
+        let size = self.note_font_size();
+        ui.vertical(|ui| {
+            ui.label(
+                egui::RichText::new("Videos:")
+                    .size(size)
+                    .underline()
+                    .strong(),
+            );
+            ui.horizontal(|ui| {
+                ui.spacing_mut().item_spacing.x = 0.0;
+                for (idx, video) in videos.iter().enumerate() {
+                    if idx != 0 {
+                        ui.label(egui::RichText::new(", ").size(size));
+                    }
+                    ui.hyperlink_to(egui::RichText::new(&video.source).size(size), &video.url);
+                }
+            });
+        });
+    }
+
+    fn render_modding(&self, ui: &mut egui::Ui, modding: &str) {
+        let size = self.note_font_size();
+        ui.vertical(|ui| {
+            ui.label(
+                egui::RichText::new("Modding Example: ")
+                    .size(size)
+                    .underline()
+                    .strong(),
+            );
+            ui.hyperlink_to(egui::RichText::new("Image").size(size), modding);
+        });
+    }
+
+    /// simple helper to make sure all slots are filled in the PhaseX.toml files
+    fn missing_helper(&self, missing: i32, ui: &mut egui::Ui) {
+        for _ in 0..missing {
+            ui.vertical(|ui| {
+                self.render_unit(ui, &Unit::forgot(), self.character_icon_size(), None, None);
+            });
+        }
+    }
+
+    fn render_unit(
+        &self,
+        ui: &mut egui::Ui,
+        unit: &Unit,
+        size: impl Into<egui::Vec2>,
+        omicron: Option<&[Omicron]>,
+        requirement: Option<&Requirement>,
+    ) {
+        ui.vertical(|ui| {
+            ui.add_sized(size, |ui: &mut egui::Ui| -> egui::Response {
+                let res = ui.add(egui::Image::new(unit.image(&self.origin)).shrink_to_fit());
+                if let Some(requirement) = requirement {
+                    let r#box = egui::Rect::from_min_max(res.rect.min, res.rect.center());
+                    ui.place(r#box, |ui: &mut egui::Ui| -> egui::Response {
+                        ui.label(self.badge(&requirement.badge(), AMBER))
+                            .on_hover_text(requirement.to_string())
+                    });
+                }
+                 if unit.id.eq_ignore_ascii_case("[ph]") {
+                    res.on_hover_text("open spots can be filled with whatever you want, but generally these spots are not needed");
+                } else if unit.id.eq_ignore_ascii_case("unavailable") {
+                    res.on_hover_text("this spot cannot be filled");
+                } else if let Some(omicron) = omicron {
+                    let r#box = egui::Rect { min: egui::Pos2::new((res.rect.max.x + res.rect.min.x) / 2., (res.rect.max.y + res.rect.min.y) / 2.), max: egui::Pos2::new(res.rect.max.x, res.rect.max.y)};
+                    ui.place(r#box, egui::Image::new(format!("{}/assets/img/icon-omicron-badge.png", self.origin)));
+                    ui.place(r#box, |ui: &mut egui::Ui| -> egui::Response {
+                        ui.label(egui::RichText::new(omicron.len().to_string()).strong().size(self.unit_font_size()))
+                    });
+                }
+
+                ui.vertical_centered(|ui| {
+                    ui.label(egui::RichText::new(&unit.name).size(self.unit_font_size()));
+                })
+                .response
+            });
+        });
+    }
+
+    fn render_squad(
+        &self,
+        ui: &mut egui::Ui,
+        team: &[Slot],
+        omicrons: Option<&[Omicrons]>,
+        requirements: Option<&[Requirement]>,
+    ) -> i32 {
+        let mut missing = 5;
+
+        ui.horizontal(|ui| {
+            for (idx, slot) in team.iter().enumerate() {
+                missing -= 1;
+                let requirement = requirements
+                    .and_then(|requirements| requirements.iter().find(|r| r.slot == idx));
+                self.render_slot(ui, slot, self.character_icon_size(), omicrons, requirement);
+            }
+        });
+
+        missing
+    }
+
+    fn render_fleet(&self, ui: &mut egui::Ui, team: &[Slot]) -> i32 {
+        let mut missing = 8;
+
+        let mut team = team.iter();
+
+        ui.horizontal(|ui| {
+            // capital ship
+            let cap = team.next().expect("must have capital ship");
+            self.render_slot(
+                ui,
+                cap,
+                self.character_icon_size() * CAPITAL_SHIP_FACTOR,
+                None,
+                None,
+            );
+            missing -= 1;
+
+            ui.vertical(|ui| {
+                // starting lineup
+                ui.label(egui::RichText::new("Starting Lineup").size(self.unit_font_size()));
+                ui.horizontal(|ui| {
+                    for starting in team.by_ref() {
+                        self.render_slot(
+                            ui,
+                            starting,
+                            self.character_icon_size() * STARTING_LINEUP_FACTOR,
+                            None,
+                            None,
+                        );
+                        missing -= 1;
+                        if missing == 4 {
+                            break;
+                        }
+                    }
+                });
+
+                ui.separator();
+
+                // reinforcements
+                ui.label(egui::RichText::new("Reinforcements").size(self.unit_font_size()));
+                ui.horizontal(|ui| {
+                    for reinforcement in team {
+                        self.render_slot(
+                            ui,
+                            reinforcement,
+                            self.character_icon_size() * REINFORCEMENT_FACTOR,
+                            None,
+                            None,
+                        );
+                        missing -= 1;
+                    }
+                });
+            });
+        });
+
+        missing
+    }
+
+    /// a team slot, slots with several options cycle
+    /// through them and list them on hover
+    fn render_slot(
+        &self,
+        ui: &mut egui::Ui,
+        slot: &Slot,
+        size: impl Into<egui::Vec2>,
+        omicrons: Option<&[Omicrons]>,
+        requirement: Option<&Requirement>,
+    ) {
+        let options = slot.options();
+        let unit = match options.len() {
+            0 | 1 => self.view.unit(slot.first()),
+            len => {
+                #[expect(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_sign_loss,
+                    reason = "the time is positive and only used to pick an option"
+                )]
+                let idx = (ui.input(|i| i.time) / SLOT_ROTATION) as usize % len;
+                ui.ctx()
+                    .request_repaint_after(std::time::Duration::from_secs_f64(SLOT_ROTATION));
+                self.view.unit(&options[idx])
+            }
+        };
+        let omi = omicrons.and_then(|omicrons| {
+            omicrons
+                .iter()
+                .find(|o| o.unit.eq_ignore_ascii_case(&unit.id))
+                .map(|o| o.omis.as_slice())
+        });
+        if options.len() <= 1 {
+            self.render_unit(ui, unit, size, omi, requirement);
+            return;
+        }
+        let title = match slot {
+            Slot::Tag { tag, .. } => format!("Any {tag}:"),
+            Slot::Unit(_) | Slot::Choice(_) => "Any of:".to_owned(),
+        };
+        let names = options
+            .iter()
+            .map(|id| self.view.unit(id).name.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        ui.scope(|ui| self.render_unit(ui, unit, size, omi, requirement))
+            .response
+            .on_hover_text(format!("{title}\n{names}"));
+    }
+
+    fn render_search(&mut self, ui: &mut egui::Ui) {
+        ui.group(|ui| {
+            ui.horizontal(|ui| {
+                let label = ui.label("Search:");
+
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.search)
+                        .hint_text("Mission ID")
+                        .desired_width(200.)
+                        .char_limit(20),
+                )
+                .labelled_by(label.id)
+                .on_hover_text(
+                    "Search for Mission IDs to quickly find the mission you need to do, \
+                     e.g. P2DCM1, P3Z for all missions on Zeffo or CM for all combat missions",
+                );
+                egui::widgets::reset_button(ui, &mut self.search, "Clear");
+            });
+
+            // search results
+            ui.vertical(|ui| {
+                self.view.search(&self.teams, &self.search);
+                for (idx, mission) in self.view.results().iter().enumerate() {
+                    if idx > 0 {
+                        ui.separator();
+                    }
+                    self.render_mission(ui, mission);
+                }
+            });
+        });
+    }
+
+    fn render_navbar(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal_wrapped(|ui| {
+            if ui.selectable_label(self.tab == Tab::Info, "Info").clicked() {
+                self.tab = Tab::Info;
+                self.set_fragment(&self.tab);
+            }
+            if ui
+                .selectable_label(self.tab == Tab::Roster, "Roster")
+                .clicked()
+            {
+                self.tab = Tab::Roster;
+                self.set_fragment(&self.tab);
+            }
+            for (idx, _) in self.teams.phases.iter().enumerate() {
+                if ui
+                    .selectable_label(
+                        self.tab == Tab::Phase(idx + 1),
+                        format!("Phase {}", idx + 1),
+                    )
+                    .clicked()
+                {
+                    self.tab = Tab::Phase(idx + 1);
+                    self.set_fragment(&self.tab);
+                }
+            }
+        });
+    }
+
+    fn set_fragment(&self, tab: &Tab) {
+        self.window
+            .location()
+            .set_hash(&tab.to_string())
+            .expect("failed to set fragment");
+    }
+}
+
+impl eframe::App for App {
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        self.view.layout = Layout::new(&resolution(&self.window));
+        if !self.errors.is_empty() {
+            egui::CentralPanel::default().show(ctx, |ui| self.render_errors(ui));
+            return;
+        }
+
+        egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
+            ui.vertical(|ui| {
+                ui.horizontal(|ui| {
+                    egui::widgets::global_theme_preference_switch(ui);
+                    ui.heading("Rise of the Empire TB Team setup");
+                });
+
+                ui.horizontal_wrapped(|ui| {
+                    self.render_navbar(ui);
+                    ui.separator();
+                    self.render_instance(ui, frame);
+                });
+            });
+        });
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.style_mut().url_in_tooltip = true;
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                self.render_search(ui);
+
+                match self.tab {
+                    Tab::Info => {
+                        let mut cache = CommonMarkCache::default();
+                        CommonMarkViewer::new().show(
+                            ui,
+                            &mut cache,
+                            include_str!("../../assets/data/INFO.md"),
+                        );
+                    }
+                    Tab::Roster => self.render_roster(ui),
+                    Tab::Phase(x) => {
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut self.board, "Assignment board");
+                            ui.checkbox(&mut self.full_auto, "Full auto only");
+                            self.render_player_select(ui);
+                            self.render_bonus_toggle(ui, x - 1);
+                        });
+                        if self.board {
+                            self.render_board(ui, x - 1);
+                        } else if let Some(player) = &self.player {
+                            self.render_plan(ui, x - 1, player);
+                        } else {
+                            self.render_phase(ui, x - 1);
+                        }
+                    }
+                }
+
+                ui.separator();
+
+                ui.vertical(|ui| {
+                    powered_by_egui_and_eframe(ui);
+                    egui::warn_if_debug_build(ui);
+                });
+            });
+        });
+    }
+
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        tracker::persist(storage, &self.tracker.borrow());
+        board::persist(storage, &self.assignments.borrow());
+        roster::persist(storage, &self.guild);
+    }
+}
+
+/// screen resolution (width, height) in pixels
+fn resolution(window: &web_sys::Window) -> Resolution {
+    Resolution {
+        height: window
+            .inner_height()
+            .expect("missing height")
+            .as_f64()
+            .expect("is number") as f32,
+        width: window
+            .inner_width()
+            .expect("missing width")
+            .as_f64()
+            .expect("is number") as f32,
+    }
+}
+
+fn powered_by_egui_and_eframe(ui: &mut egui::Ui) {
+    ui.horizontal(|ui| {
+        ui.spacing_mut().item_spacing.x = 0.0;
+        ui.add(egui::github_link_file!(
+            "https://github.com/ArckyPN/swgoh-tb/blob/main/",
+            "Source code. "
+        ));
+        ui.label("Powered by ");
+        ui.hyperlink_to("egui", "https://github.com/emilk/egui");
+        ui.label(" and ");
+        ui.hyperlink_to(
+            "eframe",
+            "https://github.com/emilk/egui/tree/master/crates/eframe",
+        );
+        ui.label(". Made by Arcky (");
+        ui.hyperlink_to("swgoh.gg", "https://swgoh.gg/p/121696617/");
+        ui.label(", ");
+        ui.hyperlink_to("YouTube", "https://www.youtube.com/@Arcky-ykcrA");
+        ui.label(", ");
+        ui.hyperlink_to(
+            "Steam",
+            "https://steamcommunity.com/profiles/76561198077815167",
+        );
+        ui.label(").");
+    });
+}
@@ -0,0 +1,64 @@
+use super::App;
+
+impl App {
+    /// which operation slots the guild can fill and who should fill them
+    pub(super) fn render_operations(&self, ui: &mut egui::Ui, idx: usize) {
+        let operations = &self.teams.operations[idx];
+        if operations.iter().all(|planet| planet.operations.is_empty()) {
+            return;
+        }
+        let Some(plans) = self.phase_plans(idx) else {
+            ui.label("Import rosters in the Roster tab to plan the operations.");
+            return;
+        };
+        let Some(plan) = &plans.operations else {
+            return;
+        };
+        let size = self.note_font_size();
+
+        egui::CollapsingHeader::new("Operations").show(ui, |ui| {
+            for planet in operations.iter() {
+                let (filled, total) = plan.filled(&planet.name);
+                ui.collapsing(
+                    format!("{} (R{}): {filled}/{total} slots", planet.name, planet.relic),
+                    |ui| {
+                        egui::Grid::new(("operations", &planet.name))
+                            .striped(true)
+                            .show(ui, |ui| {
+                                for slot in plan.slots.iter().filter(|s| s.planet == planet.name)
+                                {
+                                    let unit = self.view.unit(&slot.unit);
+                                    ui.label(
+                                        egui::RichText::new(format!("Op {}", slot.operation))
+                                            .size(size),
+                                    );
+                                    ui.label(egui::RichText::new(&unit.name).size(size));
+                                    ui.label(
+                                        egui::RichText::new(
+                                            slot.member.as_deref().unwrap_or("nobody"),
+                                        )
+                                        .size(size),
+                                    );
+                                    ui.horizontal(|ui| {
+                                        if slot.combat {
+                                            ui.label(egui::RichText::new("⚔").size(size))
+                                                .on_hover_text(
+                                                    "also used by a combat mission of this phase",
+                                                );
+                                        }
+                                        if slot.conflict {
+                                            ui.label(egui::RichText::new("⚠").size(size))
+                                                .on_hover_text(
+                                                    "the suggested member needs this unit for one of their missions",
+                                                );
+                                        }
+                                    });
+                                    ui.end_row();
+                                }
+                            });
+                    },
+                );
+            }
+        });
+    }
+}
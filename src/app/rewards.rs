@@ -0,0 +1,31 @@
+use super::App;
+
+impl App {
+    /// everything the special missions of a phase give
+    pub(super) fn render_rewards(&self, ui: &mut egui::Ui, idx: usize) {
+        let rewards = self.teams.phases[idx].rewards();
+        if rewards.is_empty() {
+            return;
+        }
+        let size = self.note_font_size();
+
+        egui::CollapsingHeader::new("Rewards").show(ui, |ui| {
+            egui::Grid::new(("rewards", idx))
+                .striped(true)
+                .show(ui, |ui| {
+                    for (planet, mission) in rewards {
+                        ui.label(egui::RichText::new(&planet.name).size(size));
+                        ui.label(
+                            egui::RichText::new(format!("{} {}", mission.id, mission.name))
+                                .size(size),
+                        );
+                        ui.label(
+                            egui::RichText::new(mission.reward_labels(&self.units).join(", "))
+                                .size(size),
+                        );
+                        ui.end_row();
+                    }
+                });
+        });
+    }
+}
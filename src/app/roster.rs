@@ -0,0 +1,196 @@
+use std::rc::Rc;
+
+use crate::{Guild, Plan, default_points};
+
+use super::{App, view::PhasePlans};
+
+const EXAMPLE: &str = r#"[[Member]]
+name = "Arcky"
+ally_code = "121696617"
+
+[[Member.Unit]]
+id = "reva"
+stars = 7
+relic = 7
+gp = 38000
+omicrons = ["u1"]
+"#;
+
+pub(super) fn restore(storage: Option<&dyn eframe::Storage>) -> Guild {
+    storage
+        .and_then(|storage| eframe::get_value(storage, Guild::KEY))
+        .unwrap_or_default()
+}
+
+pub(super) fn persist(storage: &mut dyn eframe::Storage, guild: &Guild) {
+    eframe::set_value(storage, Guild::KEY, guild);
+}
+
+impl App {
+    /// import of the guild rosters
+    pub(super) fn render_roster(&mut self, ui: &mut egui::Ui) {
+        ui.label(format!(
+            "{} rosters loaded: {}",
+            self.guild.members.len(),
+            self.guild
+                .members
+                .iter()
+                .map(|m| m.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+
+        ui.label("Paste the rosters of your guild (or only yours) in the following format:");
+        ui.code(EXAMPLE);
+
+        let id = ui.id().with("roster-import");
+        let mut text = ui.data_mut(|d| d.get_temp::<String>(id).unwrap_or_default());
+        ui.add(
+            egui::TextEdit::multiline(&mut text)
+                .code_editor()
+                .hint_text("Roster TOML")
+                .desired_rows(10)
+                .desired_width(f32::INFINITY),
+        );
+        ui.horizontal(|ui| {
+            if ui.button("Import").clicked() {
+                match Guild::from_toml(&text) {
+                    Ok(guild) => {
+                        self.guild = guild;
+                        self.view.clear_plans();
+                        self.player = None;
+                        text.clear();
+                    }
+                    Err(err) => {
+                        log::error!("failed to import rosters: {err}");
+                        ui.data_mut(|d| d.insert_temp(id.with("error"), err.to_string()));
+                    }
+                }
+            }
+            if ui.button("Export").clicked() {
+                match self.guild.to_toml() {
+                    Ok(toml) => self.download("Guild.toml", "application/toml", &toml),
+                    Err(err) => log::error!("failed to export rosters: {err}"),
+                }
+            }
+            if ui.button("Clear").clicked() {
+                self.guild = Guild::default();
+                self.view.clear_plans();
+                self.player = None;
+            }
+        });
+        if let Some(err) = ui.data(|d| d.get_temp::<String>(id.with("error"))) {
+            ui.colored_label(ui.visuals().error_fg_color, err);
+        }
+        ui.data_mut(|d| d.insert_temp(id, text));
+    }
+
+    /// selects whose plan is shown in the phase tabs
+    pub(super) fn render_player_select(&mut self, ui: &mut egui::Ui) {
+        if self.guild.members.is_empty() {
+            return;
+        }
+        egui::ComboBox::from_label("Plan for")
+            .selected_text(self.player.as_deref().unwrap_or("Recommendation"))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut self.player, None, "Recommendation");
+                for member in &self.guild.members {
+                    ui.selectable_value(&mut self.player, Some(member.name.clone()), &member.name);
+                }
+            });
+    }
+
+    /// plans, operations and deployment of the imported guild
+    /// for phase `idx`, `None` without any rosters
+    pub(super) fn phase_plans(&self, idx: usize) -> Option<Rc<PhasePlans>> {
+        if self.guild.members.is_empty() {
+            return None;
+        }
+        Some(self.view.plans(idx, || {
+            let phase = &self.teams.phases[idx];
+            let num = idx + 1;
+            let plans = self.guild.plans(phase, num, default_points);
+            let operations = &self.teams.operations[idx];
+            let operations = operations
+                .iter()
+                .any(|planet| !planet.operations.is_empty())
+                .then(|| operations.fill(phase, num, &self.guild));
+            let deployment = phase.deployment(&self.guild, &plans, operations.as_ref());
+            PhasePlans {
+                plans,
+                operations,
+                deployment,
+            }
+        }))
+    }
+
+    /// the missions the selected player should attempt
+    pub(super) fn render_plan(&self, ui: &mut egui::Ui, idx: usize, player: &str) {
+        let Some(plans) = self.phase_plans(idx) else {
+            return;
+        };
+        let Some(plan) = plans.plans.iter().find(|plan| plan.player == player) else {
+            return;
+        };
+
+        // without point data the plan only maximises the number of missions
+        let worth = if plan.has_points() && !plan.picks.is_empty() {
+            format!(" worth {} territory points", plan.points)
+        } else {
+            String::new()
+        };
+        ui.label(
+            egui::RichText::new(format!(
+                "{} can attempt {} missions{worth} without using a unit twice.",
+                plan.player,
+                plan.picks.len()
+            ))
+            .size(self.note_font_size()),
+        );
+        if !plan.impossible.is_empty() {
+            ui.label(
+                egui::RichText::new(format!(
+                    "Roster not ready for: {}",
+                    plan.impossible.join(", ")
+                ))
+                .size(self.note_font_size()),
+            );
+        }
+
+        let phase = &self.teams.phases[idx];
+        if self.is_portrait() {
+            ui.vertical(|ui| {
+                for planet in phase {
+                    self.render_plan_planet(ui, &planet.name, plan);
+                }
+            });
+        } else {
+            ui.columns(phase.num(), |ui| {
+                for (col, planet) in phase.iter().enumerate() {
+                    self.render_plan_planet(&mut ui[col], &planet.name, plan);
+                }
+            });
+        }
+    }
+
+    fn render_plan_planet(&self, ui: &mut egui::Ui, name: &str, plan: &Plan) {
+        let phase = &self.teams.phases[plan.phase - 1];
+        let Some(planet) = phase.iter().find(|planet| planet.name == name) else {
+            return;
+        };
+        ui.vertical(|ui| {
+            ui.vertical_centered(|ui| {
+                ui.label(
+                    egui::RichText::new(&planet.name)
+                        .strong()
+                        .size(self.planet_font_size()),
+                );
+            });
+            for pick in &plan.picks {
+                if planet.missions.iter().any(|m| m.id == pick.mission.id) {
+                    self.render_mission(ui, &pick.mission);
+                }
+            }
+        });
+    }
+}
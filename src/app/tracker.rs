@@ -0,0 +1,82 @@
+use crate::{Mission, Status, Tracker};
+
+use super::App;
+
+/// storage key of the last selected TB instance
+const INSTANCE_KEY: &str = "tracker-instance";
+
+/// loads the tracker of the last selected TB instance
+pub(super) fn restore(storage: Option<&dyn eframe::Storage>) -> Tracker {
+    let Some(storage) = storage else {
+        return Tracker::default();
+    };
+    let instance = eframe::get_value::<String>(storage, INSTANCE_KEY).unwrap_or_default();
+    load(storage, &instance)
+}
+
+pub(super) fn persist(storage: &mut dyn eframe::Storage, tracker: &Tracker) {
+    eframe::set_value(storage, &Tracker::key(&tracker.instance), tracker);
+    eframe::set_value(storage, INSTANCE_KEY, &tracker.instance);
+}
+
+fn load(storage: &dyn eframe::Storage, instance: &str) -> Tracker {
+    eframe::get_value(storage, &Tracker::key(instance)).unwrap_or_else(|| Tracker::new(instance))
+}
+
+impl App {
+    /// text field to select the TB instance whose progress is tracked
+    pub(super) fn render_instance(&mut self, ui: &mut egui::Ui, frame: &mut eframe::Frame) {
+        let label = ui.label("TB:");
+        let res = ui
+            .add(
+                egui::TextEdit::singleline(&mut self.instance)
+                    .hint_text("e.g. start date")
+                    .desired_width(100.)
+                    .char_limit(20),
+            )
+            .labelled_by(label.id)
+            .on_hover_text("Your mission progress is saved separately for each TB");
+
+        if res.lost_focus() && self.instance != self.tracker.borrow().instance {
+            self.switch_instance(frame);
+        }
+    }
+
+    /// saves the progress of the current TB instance and
+    /// loads the one of the newly selected instance
+    fn switch_instance(&self, frame: &mut eframe::Frame) {
+        let Some(storage) = frame.storage_mut() else {
+            self.tracker.replace(Tracker::new(&self.instance));
+            return;
+        };
+        persist(storage, &self.tracker.borrow());
+        self.tracker.replace(load(storage, &self.instance));
+    }
+
+    /// status and achieved waves of a mission
+    pub(super) fn render_progress(&self, ui: &mut egui::Ui, mission: &Mission) {
+        let mut progress = self.tracker.borrow().get(mission);
+        let size = self.note_font_size();
+
+        ui.horizontal_wrapped(|ui| {
+            for status in Status::ALL {
+                ui.selectable_value(
+                    &mut progress.status,
+                    status,
+                    egui::RichText::new(status.label()).size(size),
+                );
+            }
+            let waves = u8::try_from(mission.wave_count()).unwrap_or(u8::MAX);
+            ui.add(
+                egui::DragValue::new(&mut progress.waves)
+                    .range(0..=waves)
+                    .prefix("Waves: ")
+                    .speed(0.1),
+            );
+        });
+
+        if progress != self.tracker.borrow().get(mission) {
+            self.tracker.borrow_mut().set(mission, progress);
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    rc::Rc,
+};
+
+use crate::{Deployment, Mission, OperationsPlan, Plan, Resolution, Teams, Unit, Units};
+
+/// everything derived from the data and the window, cached
+/// so rendering a frame neither searches nor clones
+pub(super) struct View {
+    pub(super) layout: Layout,
+    /// query `results` belong to
+    query: String,
+    results: Vec<Mission>,
+    /// all units by their lower case ID
+    units: HashMap<String, Unit>,
+    missing: Unit,
+    placeholder: Unit,
+    unavailable: Unit,
+    /// plans of the imported guild by phase index
+    plans: RefCell<BTreeMap<usize, Rc<PhasePlans>>>,
+    /// Discord messages by phase index
+    discord: RefCell<BTreeMap<usize, Rc<[String]>>>,
+}
+
+/// everything planned for the imported guild in one phase
+pub(super) struct PhasePlans {
+    pub(super) plans: Vec<Plan>,
+    /// `None` if the phase has no operations
+    pub(super) operations: Option<OperationsPlan>,
+    pub(super) deployment: Deployment,
+}
+
+/// sizes depending on the window, computed once per frame
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Layout {
+    pub(super) portrait: bool,
+    pub(super) character_icon_size: f32,
+    pub(super) planet_font_size: f32,
+    pub(super) mission_font_size: f32,
+    pub(super) note_font_size: f32,
+    pub(super) unit_font_size: f32,
+}
+
+impl Layout {
+    pub(super) fn new(res: &Resolution) -> Self {
+        let portrait = res.height > res.width;
+        let width = res.width;
+        if portrait {
+            Self {
+                portrait,
+                character_icon_size: width / 8.,
+                planet_font_size: width / 10.,
+                mission_font_size: width / 15.,
+                note_font_size: width / (70. / 2.),
+                unit_font_size: width / (135. / 2.),
+            }
+        } else {
+            Self {
+                portrait,
+                character_icon_size: width / 24.,
+                planet_font_size: width / 40.,
+                mission_font_size: width / 60.,
+                note_font_size: width / 70.,
+                unit_font_size: width / 135.,
+            }
+        }
+    }
+}
+
+impl View {
+    pub(super) fn new(units: &Units, res: &Resolution) -> Self {
+        Self {
+            layout: Layout::new(res),
+            query: String::new(),
+            results: Vec::new(),
+            units: units
+                .data
+                .iter()
+                .map(|unit| (unit.id.to_ascii_lowercase(), unit.clone()))
+                .collect(),
+            missing: Unit::missing(),
+            placeholder: Unit::placeholder(),
+            unavailable: Unit::unavailable(),
+            plans: RefCell::default(),
+            discord: RefCell::default(),
+        }
+    }
+
+    /// the cached plans of phase `idx`, computed with `plan` if missing
+    pub(super) fn plans(&self, idx: usize, plan: impl FnOnce() -> PhasePlans) -> Rc<PhasePlans> {
+        Rc::clone(
+            self.plans
+                .borrow_mut()
+                .entry(idx)
+                .or_insert_with(|| Rc::new(plan())),
+        )
+    }
+
+    /// forgets all plans, e.g. after the rosters changed
+    pub(super) fn clear_plans(&self) {
+        self.plans.borrow_mut().clear();
+    }
+
+    /// the cached Discord messages of phase `idx`, computed with `export` if missing
+    pub(super) fn discord(&self, idx: usize, export: impl FnOnce() -> Vec<String>) -> Rc<[String]> {
+        Rc::clone(
+            self.discord
+                .borrow_mut()
+                .entry(idx)
+                .or_insert_with(|| export().into()),
+        )
+    }
+
+    /// forgets all Discord messages, e.g. after a bonus planet was unlocked
+    pub(super) fn clear_discord(&self) {
+        self.discord.borrow_mut().clear();
+    }
+
+    /// same as [`Units::get`] without searching or cloning
+    pub(super) fn unit(&self, id: &str) -> &Unit {
+        if id.is_empty() {
+            return &self.unavailable;
+        }
+        if id.eq_ignore_ascii_case("[ph]") {
+            return &self.placeholder;
+        }
+        self.units
+            .get(&id.to_ascii_lowercase())
+            .unwrap_or(&self.missing)
+    }
+
+    /// searches the missions matching `query` if it changed
+    pub(super) fn search(&mut self, teams: &Teams, query: &str) {
+        if self.query != query {
+            query.clone_into(&mut self.query);
+            self.results = if query.is_empty() {
+                Vec::new()
+            } else {
+                teams
+                    .search(query)
+                    .map(|(_, _, mission)| mission.clone())
+                    .collect()
+            };
+        }
+    }
+
+    /// forgets the search results, e.g. after a bonus planet was unlocked
+    pub(super) fn clear_search(&mut self) {
+        self.query.clear();
+        self.results.clear();
+    }
+
+    /// missions matching the last searched query
+    pub(super) fn results(&self) -> &[Mission] {
+        &self.results
+    }
+}
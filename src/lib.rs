@@ -1,26 +1,31 @@
 #![warn(clippy::all, rust_2018_idioms)]
 
 mod api;
+#[cfg(feature = "app")]
 mod app;
 use std::{fmt::Display, str::FromStr};
 
 pub use api::*;
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "app", target_arch = "wasm32"))]
 pub use app::App;
 
 #[derive(PartialEq, Eq)]
 pub enum Tab {
     Info,
+    Roster,
     Phase(usize),
 }
 
 impl FromStr for Tab {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s.to_ascii_lowercase().as_str() {
-            "info" => Self::Info,
-            x => Self::Phase(x.parse().unwrap_or(1)),
-        })
+        Ok(
+            match s.trim_start_matches('#').to_ascii_lowercase().as_str() {
+                "info" => Self::Info,
+                "roster" => Self::Roster,
+                x => Self::Phase(x.parse().unwrap_or(1)),
+            },
+        )
     }
 }
 
@@ -28,6 +33,7 @@ impl Display for Tab {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Info => write!(f, "info"),
+            Self::Roster => write!(f, "roster"),
             Self::Phase(x) => write!(f, "{x}"),
         }
     }
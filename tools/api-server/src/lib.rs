@@ -0,0 +1,239 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::get,
+};
+use serde::{Deserialize, Serialize};
+
+use swgoh_tb::{
+    LoadError, Mission, MissionExport, PhaseExport, Planet, PlanetExport, Teams, Unit, Units,
+};
+
+pub const ADDR: &str = "127.0.0.1:8000";
+
+struct Data {
+    teams: Teams,
+    units: Units,
+}
+
+type AppState = State<Arc<Data>>;
+type Response<T> = Result<Json<T>, (StatusCode, String)>;
+
+/// a mission together with where it is located
+#[derive(Debug, Serialize)]
+pub struct MissionHit {
+    pub phase: usize,
+    pub planet: String,
+    #[serde(flatten)]
+    pub mission: MissionExport,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchQuery {
+    q: String,
+}
+
+/// all endpoints:
+/// - `GET /phases`
+/// - `GET /phases/{num}`
+/// - `GET /phases/{num}/{planet}`, planet name or dark, mixed, light or bonus
+/// - `GET /missions/{id}`
+/// - `GET /search?q={query}`, see [`swgoh_tb::MissionQuery`]
+/// - `GET /units/{id, name or alias}`
+/// - `GET /units/{id, name or alias}/missions`
+pub fn router() -> Result<Router, LoadError> {
+    let units = Units::load()?;
+    let data = Data {
+        teams: Teams::load(&units)?,
+        units,
+    };
+    Ok(Router::new()
+        .route("/phases", get(phases))
+        .route("/phases/{num}", get(phase))
+        .route("/phases/{num}/{planet}", get(planet))
+        .route("/missions/{id}", get(mission))
+        .route("/search", get(search))
+        .route("/units/{id}", get(unit))
+        .route("/units/{id}/missions", get(unit_missions))
+        .with_state(Arc::new(data)))
+}
+
+fn not_found(what: &str) -> (StatusCode, String) {
+    (StatusCode::NOT_FOUND, format!("{what} not found"))
+}
+
+fn hit(data: &Data, (phase, planet, mission): (usize, &Planet, &Mission)) -> MissionHit {
+    MissionHit {
+        phase,
+        planet: planet.name.clone(),
+        mission: mission.export(&data.units),
+    }
+}
+
+async fn phases(State(data): AppState) -> Json<Vec<PhaseExport>> {
+    Json(
+        data.teams
+            .phases
+            .iter()
+            .enumerate()
+            .map(|(idx, phase)| phase.export(idx + 1, &data.units))
+            .collect(),
+    )
+}
+
+async fn phase(State(data): AppState, Path(num): Path<usize>) -> Response<PhaseExport> {
+    let phase = data
+        .teams
+        .phases
+        .get(num.wrapping_sub(1))
+        .ok_or_else(|| not_found(&format!("phase {num}")))?;
+    Ok(Json(phase.export(num, &data.units)))
+}
+
+async fn planet(
+    State(data): AppState,
+    Path((num, name)): Path<(usize, String)>,
+) -> Response<PlanetExport> {
+    let phase = data
+        .teams
+        .phases
+        .get(num.wrapping_sub(1))
+        .ok_or_else(|| not_found(&format!("phase {num}")))?;
+    let planet = match name.to_ascii_lowercase().as_str() {
+        "dark" => Some(&phase.dark),
+        "mixed" => Some(&phase.mixed),
+        "light" => Some(&phase.light),
+        "bonus" => phase.bonus.as_ref(),
+        _ => phase.iter().find(|p| p.name.eq_ignore_ascii_case(&name)),
+    }
+    .ok_or_else(|| not_found(&format!("planet {name}")))?;
+    Ok(Json(planet.export(&data.units)))
+}
+
+/// all teams of a mission, alternatives share the same ID
+async fn mission(State(data): AppState, Path(id): Path<String>) -> Response<Vec<MissionHit>> {
+    let hits = data
+        .teams
+        .missions()
+        .filter(|(_, _, m)| m.id.eq_ignore_ascii_case(&id))
+        .map(|found| hit(&data, found))
+        .collect::<Vec<_>>();
+    if hits.is_empty() {
+        return Err(not_found(&format!("mission {id}")));
+    }
+    Ok(Json(hits))
+}
+
+async fn search(State(data): AppState, Query(query): Query<SearchQuery>) -> Json<Vec<MissionHit>> {
+    Json(
+        data.teams
+            .search(&query.q)
+            .map(|found| hit(&data, found))
+            .collect(),
+    )
+}
+
+async fn unit(State(data): AppState, Path(id): Path<String>) -> Response<Unit> {
+    let unit = data
+        .units
+        .find(&id)
+        .ok_or_else(|| not_found(&format!("unit {id}")))?;
+    Ok(Json(unit.clone()))
+}
+
+async fn unit_missions(State(data): AppState, Path(id): Path<String>) -> Response<Vec<MissionHit>> {
+    let unit = data
+        .units
+        .find(&id)
+        .ok_or_else(|| not_found(&format!("unit {id}")))?;
+    Ok(Json(
+        data.teams
+            .missions_with(&unit.id)
+            .map(|found| hit(&data, found))
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::Body, http::Request};
+    use http_body_util::BodyExt as _;
+    use serde_json::Value;
+    use tower::ServiceExt as _;
+
+    use super::*;
+
+    /// the status and JSON body of `GET uri`
+    async fn get(uri: &str) -> (StatusCode, Value) {
+        let response = router()
+            .expect("data files load")
+            .oneshot(Request::get(uri).body(Body::empty()).expect("a request"))
+            .await
+            .expect("a response");
+        let status = response.status();
+        let body = response
+            .into_body()
+            .collect()
+            .await
+            .expect("a body")
+            .to_bytes();
+        let json = serde_json::from_slice(&body).unwrap_or(Value::Null);
+        (status, json)
+    }
+
+    fn ids(json: &Value) -> Vec<&str> {
+        json.as_array()
+            .expect("a list")
+            .iter()
+            .filter_map(|hit| hit["id"].as_str())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn phases_are_listed() {
+        let (status, json) = get("/phases").await;
+        assert_eq!(status, StatusCode::OK, "status");
+        assert_eq!(
+            json.as_array().map(Vec::len),
+            Some(6),
+            "all phases are listed"
+        );
+    }
+
+    #[tokio::test]
+    async fn planets_are_found_by_alignment() {
+        let (status, json) = get("/phases/3/bonus").await;
+        assert_eq!(status, StatusCode::OK, "status");
+        assert_eq!(json["name"], "Zeffo", "the bonus planet of phase 3");
+    }
+
+    #[tokio::test]
+    async fn missions_are_located() {
+        let (status, json) = get("/missions/P3BCM").await;
+        assert_eq!(status, StatusCode::OK, "status");
+        assert_eq!(json[0]["phase"], 3, "phase");
+        assert_eq!(json[0]["planet"], "Zeffo", "planet");
+    }
+
+    #[tokio::test]
+    async fn search_understands_partial_ids() {
+        let (status, json) = get("/search?q=P3ZCM").await;
+        assert_eq!(status, StatusCode::OK, "status");
+        assert_eq!(ids(&json), ["P3BCM"], "kind on a planet");
+
+        let (_, json) = get("/search?q=CM").await;
+        assert!(
+            ids(&json).len() > 6,
+            "combat missions of all phases: {json}"
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_units_are_not_found() {
+        let (status, _) = get("/units/nobody").await;
+        assert_eq!(status, StatusCode::NOT_FOUND, "status");
+    }
+}
@@ -0,0 +1,14 @@
+use anyhow::Result;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    pretty_env_logger::init();
+
+    let addr = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| api_server::ADDR.to_owned());
+    let listener = tokio::net::TcpListener::bind(&addr).await?;
+    log::info!("listening on {addr}");
+    axum::serve(listener, api_server::router()?).await?;
+    Ok(())
+}
@@ -251,7 +251,10 @@ impl Crawler {
             data: self.units.clone(),
         };
         units.data.sort_by(|a, b| a.name.cmp(&b.name));
-        let s = toml::to_string(&units)?;
+        let s = format!(
+            "#:schema ../schema/Units.schema.json\n\n{}",
+            toml::to_string(&units)?
+        );
 
         std::fs::write(&self.units_toml, s)?;
         Ok(())
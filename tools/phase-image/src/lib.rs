@@ -0,0 +1,253 @@
+use std::path;
+
+use anyhow::{Context as _, Result};
+use resvg::{tiny_skia, usvg};
+use svg::{
+    Document,
+    node::element::{Group, Image, Rectangle, Text},
+};
+use swgoh_tb::{Mission, Phase, Planet, Units};
+
+/// the asset folder of the page, relative to this tool
+pub const ASSETS: &str = "../../assets";
+
+pub const BACKGROUND: &str = "#1b1b1b";
+pub const CARD: &str = "#2b2b2b";
+pub const TEXT: &str = "#ffffff";
+pub const RELIC: &str = "#a4161a";
+pub const STROKE: &str = "#000000";
+/// font families to try in order, the first one
+/// available on the system is used
+pub const FONT: &str = "Arial, Helvetica, DejaVu Sans, Liberation Sans, sans-serif";
+
+/// width of a single planet column
+const COLUMN: f32 = 480.;
+const PADDING: f32 = 12.;
+const HEADER: f32 = 60.;
+const TITLE: f32 = 28.;
+const PORTRAIT: f32 = 80.;
+const FLEET_PORTRAIT: f32 = 48.;
+/// height of the relic badge
+const LABEL: f32 = 28.;
+/// scale of the PNG compared to the SVG
+const SCALE: f32 = 2.;
+
+/// composes the recommendation of a phase into an SVG,
+/// each planet is rendered as a column of mission cards
+///
+/// unit portraits are referenced by their file name and
+/// need to be resolved against `assets/img`, see [`save_png`]
+pub fn render(phase: &Phase, num: usize, units: &Units) -> Document {
+    let mut planets = Group::new();
+    let mut height: f32 = 0.;
+
+    let title = Text::new(format!("Rise of the Empire - Phase {num}"))
+        .set("x", PADDING)
+        .set("y", HEADER * 0.66)
+        .set("font-size", HEADER * 0.5)
+        .set("font-weight", "bold")
+        .set("fill", TEXT);
+
+    for (col, planet) in phase.iter().enumerate() {
+        let (group, h) = planet_column(planet, units);
+        planets = planets.add(group.set(
+            "transform",
+            format!("translate({}, {HEADER})", col as f32 * COLUMN),
+        ));
+        height = height.max(h);
+    }
+
+    let width = phase.num() as f32 * COLUMN;
+    let height = height + HEADER;
+
+    Document::new()
+        .set("viewBox", (0, 0, width, height))
+        .set("width", width)
+        .set("height", height)
+        .set("font-family", FONT)
+        .add(
+            Rectangle::new()
+                .set("width", width)
+                .set("height", height)
+                .set("fill", BACKGROUND),
+        )
+        .add(title)
+        .add(planets)
+}
+
+/// renders `svg` as PNG to `file`, `assets` is the asset
+/// folder of the page used to load the unit portraits
+///
+/// adapted from [resvg::example](https://github.com/linebender/resvg/blob/main/crates/resvg/examples/minimal.rs)
+pub fn save_png<P, P2>(svg: &Document, assets: P, file: P2) -> Result<()>
+where
+    P: AsRef<path::Path>,
+    P2: AsRef<path::Path>,
+{
+    let mut opt = usvg::Options {
+        resources_dir: Some(assets.as_ref().join("img")),
+        ..Default::default()
+    };
+    opt.fontdb_mut().load_system_fonts();
+
+    let tree = usvg::Tree::from_str(&svg.to_string(), &opt).context("failed to parse svg")?;
+
+    let size = tree
+        .size()
+        .to_int_size()
+        .scale_by(SCALE)
+        .context("image too large")?;
+    let mut pixmap =
+        tiny_skia::Pixmap::new(size.width(), size.height()).context("failed to create pixmap")?;
+
+    resvg::render(
+        &tree,
+        tiny_skia::Transform::from_scale(SCALE, SCALE),
+        &mut pixmap.as_mut(),
+    );
+    pixmap.save_png(file).context("failed to save png")?;
+
+    Ok(())
+}
+
+/// a planet column and its height
+fn planet_column(planet: &Planet, units: &Units) -> (Group, f32) {
+    let mut group = Group::new().add(
+        Text::new(&planet.name)
+            .set("x", COLUMN / 2.)
+            .set("y", TITLE)
+            .set("font-size", TITLE)
+            .set("font-weight", "bold")
+            .set("text-anchor", "middle")
+            .set("fill", TEXT),
+    );
+
+    let mut y = TITLE + PADDING;
+    for mission in &planet.missions {
+        let (card, h) = mission_card(mission, units);
+        group = group.add(card.set("transform", format!("translate({PADDING}, {y})")));
+        y += h + PADDING;
+    }
+
+    (group, y)
+}
+
+/// a mission card and its height
+fn mission_card(mission: &Mission, units: &Units) -> (Group, f32) {
+    let width = COLUMN - 2. * PADDING;
+    let portrait = if mission.is_fleet() {
+        FLEET_PORTRAIT
+    } else {
+        PORTRAIT
+    };
+    let height = TITLE + portrait + 2. * PADDING;
+
+    let mut card = Group::new()
+        .add(
+            Rectangle::new()
+                .set("width", width)
+                .set("height", height)
+                .set("rx", PADDING / 2.)
+                .set("fill", CARD)
+                .set("stroke", STROKE),
+        )
+        .add(
+            Text::new(format!("{} ({})", mission.name, mission.id))
+                .set("x", PADDING)
+                .set("y", TITLE * 0.8)
+                .set("font-size", TITLE * 0.6)
+                .set("fill", TEXT),
+        )
+        .add(relic_badge(mission, width));
+
+    // a static image can only show the preferred unit of a slot
+    for (idx, slot) in mission
+        .team
+        .iter()
+        .filter(|slot| !slot.is_unavailable())
+        .enumerate()
+    {
+        let unit = units.get(slot.first());
+        let x = PADDING + idx as f32 * (portrait + PADDING / 2.);
+        let y = TITLE + PADDING / 2.;
+
+        card = card.add(
+            Image::new()
+                .set("href", unit.image.as_str())
+                .set("x", x)
+                .set("y", y)
+                .set("width", portrait)
+                .set("height", portrait),
+        );
+
+        let omicrons = mission
+            .omicrons
+            .iter()
+            .flatten()
+            .filter(|omicron| omicron.unit.eq_ignore_ascii_case(&unit.id))
+            .map(|omicron| omicron.omis.len())
+            .sum::<usize>();
+        if omicrons > 0 {
+            card = card.add(omicron_badge(
+                omicrons,
+                x + portrait / 2.,
+                y + portrait / 2.,
+                portrait / 2.,
+            ));
+        }
+    }
+
+    (card, height)
+}
+
+/// relic requirement in the top right corner of a card
+fn relic_badge(mission: &Mission, width: f32) -> Group {
+    let label = match mission.relic() {
+        Some(relic) => format!("R{relic}+"),
+        None => "7*".to_owned(),
+    };
+    let w = LABEL * 2.;
+
+    Group::new()
+        .add(
+            Rectangle::new()
+                .set("x", width - w - PADDING / 2.)
+                .set("y", PADDING / 4.)
+                .set("width", w)
+                .set("height", LABEL * 0.8)
+                .set("rx", PADDING / 2.)
+                .set("fill", RELIC),
+        )
+        .add(
+            Text::new(label)
+                .set("x", width - w / 2. - PADDING / 2.)
+                .set("y", LABEL * 0.65)
+                .set("font-size", LABEL * 0.55)
+                .set("font-weight", "bold")
+                .set("text-anchor", "middle")
+                .set("fill", TEXT),
+        )
+}
+
+/// the omicron badge used by the page with the amount of
+/// required omicrons on top of it
+fn omicron_badge(count: usize, x: f32, y: f32, size: f32) -> Group {
+    Group::new()
+        .add(
+            Image::new()
+                .set("href", "icon-omicron-badge.png")
+                .set("x", x)
+                .set("y", y)
+                .set("width", size)
+                .set("height", size),
+        )
+        .add(
+            Text::new(count.to_string())
+                .set("x", x + size / 2.)
+                .set("y", y + size * 0.65)
+                .set("font-size", size * 0.5)
+                .set("font-weight", "bold")
+                .set("text-anchor", "middle")
+                .set("fill", TEXT),
+        )
+}
@@ -0,0 +1,29 @@
+use anyhow::{Context as _, Result};
+
+use phase_image::{ASSETS, render, save_png};
+use swgoh_tb::{Teams, Units};
+
+fn main() -> Result<()> {
+    let units = Units::load()?;
+    let teams = Teams::load(&units)?;
+
+    let mut phases = std::env::args()
+        .skip(1)
+        .map(|arg| arg.parse::<usize>().context("phases must be numbers"))
+        .collect::<Result<Vec<_>>>()?;
+    if phases.is_empty() {
+        phases = (1..=teams.phases.len()).collect();
+    }
+
+    for num in phases {
+        let phase = teams
+            .phases
+            .get(num.wrapping_sub(1))
+            .with_context(|| format!("there is no phase {num}"))?;
+        let svg = render(phase, num, &units);
+        save_png(&svg, ASSETS, format!("Phase{num}.png"))?;
+        println!("saved Phase{num}.png");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use std::{fs, path::Path};
+
+use anyhow::Result;
+use schemars::{JsonSchema, schema_for};
+use swgoh_tb::{Operations, Phase, Units};
+
+const SCHEMAS: &str = "../../assets/schema";
+
+fn main() -> Result<()> {
+    fs::create_dir_all(SCHEMAS)?;
+    write::<Phase>("Phase")?;
+    write::<Operations>("Operations")?;
+    write::<Units>("Units")?;
+    Ok(())
+}
+
+fn write<T: JsonSchema>(name: &str) -> Result<()> {
+    let file = Path::new(SCHEMAS).join(format!("{name}.schema.json"));
+    let mut json = serde_json::to_string_pretty(&schema_for!(T))?;
+    json.push('\n');
+    fs::write(&file, json)?;
+    println!("saved {}", file.display());
+    Ok(())
+}